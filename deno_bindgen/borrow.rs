@@ -0,0 +1,72 @@
+//! Borrowed arguments whose mutability is only known at the call site,
+//! e.g. inside an `Option`.
+
+/// Borrows a slice from a JavaScript typed array.
+pub trait FromBuffer {
+  /// # Safety
+  ///
+  /// `ptr` must be valid for `len` bytes, unless `len` is zero.
+  unsafe fn from_buffer(ptr: *const (), len: usize) -> Self;
+}
+
+/// The number of elements in a buffer of `len` bytes.
+fn elements<T>(ptr: *const (), len: usize) -> usize {
+  // Typed arrays are always aligned to their element size and hold whole
+  // elements, anything else means the wrong kind of array was passed in.
+  let size = std::mem::size_of::<T>();
+  assert!(
+    len.is_multiple_of(size),
+    "buffer of {len} bytes is not a multiple of {size} bytes"
+  );
+  assert!(
+    (ptr as usize).is_multiple_of(std::mem::align_of::<T>()),
+    "buffer is not aligned to {} bytes",
+    std::mem::align_of::<T>()
+  );
+  len / size
+}
+
+impl<T> FromBuffer for &[T] {
+  unsafe fn from_buffer(ptr: *const (), len: usize) -> Self {
+    // Empty buffers may be passed as null pointers.
+    if len == 0 {
+      return &[];
+    }
+    std::slice::from_raw_parts(ptr as *const T, elements::<T>(ptr, len))
+  }
+}
+
+impl<T> FromBuffer for &mut [T] {
+  unsafe fn from_buffer(ptr: *const (), len: usize) -> Self {
+    if len == 0 {
+      return &mut [];
+    }
+    std::slice::from_raw_parts_mut(ptr as *mut T, elements::<T>(ptr, len))
+  }
+}
+
+/// A slice returned from a method and viewed in place by JavaScript, through
+/// `Deno.UnsafePointerView.getArrayBuffer`.
+///
+/// The view is only valid as long as the object the slice is borrowed from.
+#[repr(C)]
+pub struct BorrowedSlice {
+  ptr: *const u8,
+  /// The length in bytes.
+  len: usize,
+}
+
+impl<T> From<&[T]> for BorrowedSlice {
+  fn from(slice: &[T]) -> Self {
+    Self {
+      ptr: slice.as_ptr() as *const u8,
+      len: std::mem::size_of_val(slice),
+    }
+  }
+}
+
+impl<T> From<&mut [T]> for BorrowedSlice {
+  fn from(slice: &mut [T]) -> Self {
+    Self::from(&*slice)
+  }
+}
@@ -0,0 +1,46 @@
+use std::mem::ManuallyDrop;
+
+/// A heap allocated buffer whose ownership is handed over to JavaScript.
+///
+/// The generated bindings copy the bytes out and release the allocation
+/// through `__deno_bindgen_free`.
+#[repr(C)]
+pub struct OwnedBuffer {
+  ptr: *mut u8,
+  /// The length in bytes, read by the bindings.
+  len: usize,
+  cap: usize,
+  /// Rebuilds and drops the vector the buffer was made from, so that it is
+  /// deallocated with the layout of its element type.
+  free: unsafe fn(*mut u8, usize, usize),
+}
+
+unsafe fn free<T>(ptr: *mut u8, len: usize, cap: usize) {
+  // Elements are `Copy`, so zero-sized ones have nothing to drop.
+  let len = len.checked_div(std::mem::size_of::<T>()).unwrap_or(0);
+  drop(Vec::from_raw_parts(ptr as *mut T, len, cap));
+}
+
+impl OwnedBuffer {
+  /// Hands over a vector of plain numbers, such as a `Vec<u8>` or
+  /// `Vec<f64>`.
+  pub fn into_raw<T: Copy>(values: Vec<T>) -> *mut Self {
+    let mut values = ManuallyDrop::new(values);
+    Box::into_raw(Box::new(Self {
+      ptr: values.as_mut_ptr() as *mut u8,
+      len: std::mem::size_of_val(values.as_slice()),
+      cap: values.capacity(),
+      free: free::<T>,
+    }))
+  }
+}
+
+#[no_mangle]
+unsafe extern "C" fn __deno_bindgen_free(buf: *mut OwnedBuffer) {
+  if buf.is_null() {
+    return;
+  }
+
+  let buf = Box::from_raw(buf);
+  (buf.free)(buf.ptr, buf.len, buf.cap);
+}
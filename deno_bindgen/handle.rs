@@ -0,0 +1,122 @@
+//! Conversions between Rust values and what JavaScript holds on to.
+//!
+//! Class instances are boxed and handed out as opaque handles, while
+//! `#[deno_bindgen(serde)]` structs travel as JSON and C-like enums as their
+//! discriminant.
+//!
+//! With the `safe-handles` feature, class handles are checked against the
+//! live objects before use, see the `slab` module. Stale or foreign handles then
+//! panic, which is reported to JavaScript as an exception.
+
+#[cfg(feature = "safe-handles")]
+use std::any::TypeId;
+
+use crate::BindgenType;
+
+/// Boxes a class instance, returning its handle.
+pub fn new_handle<T: BindgenType + 'static>(value: T) -> *const () {
+  let ptr = Box::into_raw(Box::new(value)) as *const ();
+  #[cfg(feature = "safe-handles")]
+  let ptr = crate::slab::insert(ptr, TypeId::of::<T>());
+  ptr
+}
+
+#[cfg(feature = "safe-handles")]
+#[track_caller]
+fn invalid<T: BindgenType>() -> ! {
+  panic!(
+    "invalid {} handle, the object may have been consumed or disposed",
+    T::type_name()
+  )
+}
+
+/// Borrows the instance behind a handle.
+///
+/// # Safety
+///
+/// Without `safe-handles`, `handle` must point to a live `T` allocated by
+/// the bindings.
+#[track_caller]
+pub unsafe fn handle_mut<'a, T: BindgenType + 'static>(
+  handle: *const (),
+) -> &'a mut T {
+  #[cfg(feature = "safe-handles")]
+  let Some(handle) = crate::slab::get(handle, TypeId::of::<T>()) else {
+    invalid::<T>()
+  };
+  debug_assert!(!handle.is_null());
+  &mut *(handle as *mut T)
+}
+
+/// Takes the instance out of its handle, which is no longer valid
+/// afterwards.
+///
+/// # Safety
+///
+/// See [`handle_mut`].
+#[track_caller]
+pub unsafe fn take_handle<T: BindgenType + 'static>(handle: *const ()) -> T {
+  #[cfg(feature = "safe-handles")]
+  let Some(handle) = crate::slab::remove(handle, TypeId::of::<T>()) else {
+    invalid::<T>()
+  };
+  debug_assert!(!handle.is_null());
+  *Box::from_raw(handle as *mut T)
+}
+
+/// Drops the instance behind a handle, null handles are ignored.
+///
+/// # Safety
+///
+/// See [`handle_mut`].
+#[track_caller]
+pub unsafe fn drop_handle<T: BindgenType + 'static>(handle: *const ()) {
+  if !handle.is_null() {
+    drop(take_handle::<T>(handle));
+  }
+}
+
+/// Converts an argument from the raw value passed by JavaScript.
+pub trait FromHandle: Sized {
+  type Raw;
+
+  /// # Safety
+  ///
+  /// Handles must point to a live instance allocated by the bindings.
+  unsafe fn from_handle(raw: Self::Raw) -> Self;
+}
+
+impl<T: BindgenType + 'static> FromHandle for &T {
+  type Raw = *const ();
+
+  #[track_caller]
+  unsafe fn from_handle(ptr: *const ()) -> Self {
+    handle_mut::<T>(ptr)
+  }
+}
+
+impl<T: BindgenType + 'static> FromHandle for &mut T {
+  type Raw = *const ();
+
+  #[track_caller]
+  unsafe fn from_handle(ptr: *const ()) -> Self {
+    handle_mut::<T>(ptr)
+  }
+}
+
+/// Hands a returned value over to JavaScript.
+///
+/// `Raw` is also the FFI type arguments of this type are passed as.
+pub trait IntoHandle {
+  type Raw;
+
+  fn into_handle(self) -> Self::Raw;
+}
+
+/// Implemented by fieldless `#[deno_bindgen]` enums, the only custom types
+/// allowed in `#[deno_bindgen(property)]` fields.
+#[diagnostic::on_unimplemented(
+  message = "`{Self}` is not a fieldless #[deno_bindgen] enum",
+  note = "#[deno_bindgen(property)] is only supported on fieldless enum fields"
+)]
+pub trait FieldlessEnum {}
@@ -0,0 +1,51 @@
+use std::ffi::c_char;
+use std::ffi::CStr;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::OwnedBuffer;
+
+/// Decodes a `#[deno_bindgen(serde)]` argument.
+///
+/// # Safety
+///
+/// `ptr` must point to a NUL-terminated JSON string. The generated bindings
+/// can rely on this as `JSON.stringify` escapes control characters.
+pub unsafe fn from_json<T: DeserializeOwned>(ptr: *const ()) -> T {
+  let json = CStr::from_ptr(ptr as *const c_char);
+  serde_json::from_slice(json.to_bytes())
+    .expect("argument does not match its interface")
+}
+
+/// Encodes a `#[deno_bindgen(serde)]` return value into an `OwnedBuffer`.
+pub fn into_json<T: Serialize>(value: &T) -> *const () {
+  let json = serde_json::to_vec(value).expect("failed to serialize value");
+  OwnedBuffer::into_raw(json) as *const ()
+}
+
+/// Splits a data enum argument into its `type` tag and fields.
+///
+/// # Safety
+///
+/// See [`from_json`].
+pub unsafe fn from_tagged(ptr: *const ()) -> (String, serde_json::Value) {
+  let mut value: serde_json::Value = from_json(ptr);
+  let tag = match value.get_mut("type").map(serde_json::Value::take) {
+    Some(serde_json::Value::String(tag)) => tag,
+    _ => panic!("argument is missing its `type` tag"),
+  };
+  (tag, value)
+}
+
+/// Decodes a field of a data enum variant, see [`from_tagged`].
+pub fn from_field<T: DeserializeOwned>(
+  fields: &mut serde_json::Value,
+  name: &str,
+) -> T {
+  let field = fields
+    .get_mut(name)
+    .map(serde_json::Value::take)
+    .unwrap_or_default();
+  serde_json::from_value(field).expect("argument does not match its type")
+}
@@ -1,18 +1,82 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
 pub use ::serde_json;
+pub use borrow::BorrowedSlice;
+pub use borrow::FromBuffer;
+pub use buffer::OwnedBuffer;
 use deno_bindgen_ir::codegen::Options;
 pub use deno_bindgen_ir::*;
 pub use deno_bindgen_macro::deno_bindgen;
+pub use handle::drop_handle;
+pub use handle::handle_mut;
+pub use handle::new_handle;
+pub use handle::take_handle;
+pub use handle::FieldlessEnum;
+pub use handle::FromHandle;
+pub use handle::IntoHandle;
+pub use json::from_field;
+pub use json::from_json;
+pub use json::from_tagged;
+pub use json::into_json;
 pub use linkme;
 use linkme::distributed_slice;
+pub use option::Optional;
+pub use panic::catch_unwind;
+#[cfg(feature = "async")]
+pub use runtime::block_on;
+
+mod borrow;
+mod buffer;
+mod handle;
+mod json;
+mod option;
+mod panic;
+#[cfg(feature = "async")]
+mod runtime;
+#[cfg(feature = "safe-handles")]
+mod slab;
 
 #[distributed_slice]
 pub static INVENTORY: [Inventory];
 
+/// Drives an exported `async fn` to completion.
+#[cfg(feature = "async")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __block_on {
+  ($future:expr) => {
+    $crate::block_on($future)
+  };
+}
+
+#[cfg(not(feature = "async"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __block_on {
+  ($future:expr) => {
+    ::core::compile_error!(
+      "exporting an `async fn` requires the `async` feature of deno_bindgen"
+    )
+  };
+}
+
 pub trait BindgenType {
+  /// The `///` comments on the type, added to its class.
+  const DOCS: &'static str = "";
+  /// The note of a `#[deprecated]` type, added to its class.
+  const DEPRECATED: Option<&'static str> = None;
+  /// The class name, if it differs from the type name.
+  const RENAME: Option<&'static str> = None;
+  /// Whether instances are freed once garbage collected.
+  const FINALIZE: bool = false;
+
   fn type_name() -> &'static str;
 }
 
+#[no_mangle]
+extern "C" fn deno_bindgen_ir_version() -> *const std::ffi::c_char {
+  deno_bindgen_ir::codegen::VERSION.as_ptr().cast()
+}
+
 #[no_mangle]
 fn init_deno_bindgen(opt: Options) {
   deno_bindgen_ir::codegen::generate(&INVENTORY, opt).unwrap();
@@ -0,0 +1,25 @@
+use std::mem::MaybeUninit;
+
+/// An `Option` of a number, enum or class handle, returned as a
+/// `{ struct: ["u8", T] }`.
+#[repr(C)]
+pub struct Optional<T> {
+  is_some: u8,
+  value: MaybeUninit<T>,
+}
+
+impl<T> From<Option<T>> for Optional<T> {
+  fn from(value: Option<T>) -> Self {
+    match value {
+      Some(value) => Self {
+        is_some: 1,
+        value: MaybeUninit::new(value),
+      },
+      // Zeroed, so that no uninitialized bytes are handed to JavaScript.
+      None => Self {
+        is_some: 0,
+        value: MaybeUninit::zeroed(),
+      },
+    }
+  }
+}
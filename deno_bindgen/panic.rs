@@ -0,0 +1,64 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::panic::AssertUnwindSafe;
+use std::panic::PanicHookInfo;
+use std::sync::Once;
+
+use crate::OwnedBuffer;
+
+thread_local! {
+  /// Where the last panic on this thread happened.
+  static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Records panic locations, which are not part of the payload, before
+/// handing over to the previous hook.
+fn install_hook() {
+  static HOOK: Once = Once::new();
+  HOOK.call_once(|| {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info: &PanicHookInfo| {
+      let location = info.location().map(|location| location.to_string());
+      LOCATION.with(|slot| *slot.borrow_mut() = location);
+      previous(info);
+    }));
+  });
+}
+
+fn message(payload: &(dyn Any + Send)) -> &str {
+  if let Some(message) = payload.downcast_ref::<&str>() {
+    message
+  } else if let Some(message) = payload.downcast_ref::<String>() {
+    message
+  } else {
+    "Box<dyn Any>"
+  }
+}
+
+/// Runs the body of a symbol, reporting a panic through its error slot
+/// rather than unwinding into Deno.
+///
+/// # Safety
+///
+/// `error` must be valid for writes, and `R` must be valid when zeroed as
+/// it is returned in place of a value on panic.
+pub unsafe fn catch_unwind<R>(
+  error: *mut *mut OwnedBuffer,
+  f: impl FnOnce() -> R,
+) -> R {
+  install_hook();
+
+  match std::panic::catch_unwind(AssertUnwindSafe(f)) {
+    Ok(ret) => ret,
+    Err(payload) => {
+      let message = match LOCATION.with(|slot| slot.borrow_mut().take()) {
+        Some(location) => {
+          format!("panicked at {location}: {}", message(&*payload))
+        }
+        None => format!("panicked: {}", message(&*payload)),
+      };
+      *error = OwnedBuffer::into_raw(message.into_bytes());
+      std::mem::zeroed()
+    }
+  }
+}
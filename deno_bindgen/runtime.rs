@@ -0,0 +1,22 @@
+use std::future::Future;
+use std::sync::OnceLock;
+
+use tokio::runtime::Runtime;
+
+fn runtime() -> &'static Runtime {
+  static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+  RUNTIME.get_or_init(|| {
+    tokio::runtime::Builder::new_multi_thread()
+      .enable_all()
+      .build()
+      .expect("failed to start the async runtime")
+  })
+}
+
+/// Drives the future of an `async fn` to completion.
+///
+/// `async fn`s are exported as non-blocking symbols, so this runs on the
+/// thread Deno dispatches the call to while the caller awaits a promise.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+  runtime().block_on(future)
+}
@@ -0,0 +1,98 @@
+//! Generation-checked handles, enabled by the `safe-handles` feature.
+//!
+//! Instead of the address of the boxed object, JavaScript holds on to a slot
+//! index in the upper half of the handle and the slot's generation in the
+//! lower half. Freeing a slot bumps its generation, so stale handles no
+//! longer match and can be rejected instead of read.
+
+use std::any::TypeId;
+use std::sync::Mutex;
+
+#[cfg(not(target_pointer_width = "64"))]
+compile_error!("the `safe-handles` feature requires a 64-bit target");
+
+struct Slot {
+  generation: u32,
+  /// The boxed object and its type, `None` while the slot is free.
+  value: Option<(*const (), TypeId)>,
+}
+
+struct Slab {
+  slots: Vec<Slot>,
+  free: Vec<usize>,
+}
+
+// Objects are only reached through the handles, like the boxes they
+// replace.
+unsafe impl Send for Slab {}
+
+static SLAB: Mutex<Slab> = Mutex::new(Slab {
+  slots: Vec::new(),
+  free: Vec::new(),
+});
+
+fn lock() -> std::sync::MutexGuard<'static, Slab> {
+  // Nothing panics while the lock is held.
+  SLAB.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+/// Slot indices start at one, so that a null handle is never valid.
+fn encode(index: usize, generation: u32) -> *const () {
+  (((index + 1) << 32) | generation as usize) as *const ()
+}
+
+fn decode(handle: *const ()) -> Option<(usize, u32)> {
+  let handle = handle as usize;
+  let index = (handle >> 32).checked_sub(1)?;
+  Some((index, handle as u32))
+}
+
+pub fn insert(ptr: *const (), ty: TypeId) -> *const () {
+  let mut slab = lock();
+  let index = match slab.free.pop() {
+    Some(index) => index,
+    None => {
+      slab.slots.push(Slot {
+        generation: 0,
+        value: None,
+      });
+      slab.slots.len() - 1
+    }
+  };
+
+  let slot = &mut slab.slots[index];
+  slot.value = Some((ptr, ty));
+  encode(index, slot.generation)
+}
+
+/// The slot index and object behind a live handle of type `ty`.
+fn find(
+  slab: &Slab,
+  handle: *const (),
+  ty: TypeId,
+) -> Option<(usize, *const ())> {
+  let (index, generation) = decode(handle)?;
+  let slot = slab.slots.get(index)?;
+  match slot.value {
+    Some((ptr, slot_ty)) if slot.generation == generation && slot_ty == ty => {
+      Some((index, ptr))
+    }
+    _ => None,
+  }
+}
+
+/// The object behind a live handle of type `ty`.
+pub fn get(handle: *const (), ty: TypeId) -> Option<*const ()> {
+  find(&lock(), handle, ty).map(|(_, ptr)| ptr)
+}
+
+/// Frees the slot of a live handle, returning the object.
+pub fn remove(handle: *const (), ty: TypeId) -> Option<*const ()> {
+  let mut slab = lock();
+  let (index, ptr) = find(&slab, handle, ty)?;
+  let slot = &mut slab.slots[index];
+  slot.value = None;
+  slot.generation = slot.generation.wrapping_add(1);
+  slab.free.push(index);
+  Some(ptr)
+}
@@ -0,0 +1,15 @@
+use deno_bindgen::deno_bindgen;
+
+#[deno_bindgen]
+struct Foo;
+
+// Impls take no arguments, classes are renamed on the struct.
+#[deno_bindgen(rename = "Bar")]
+impl Foo {
+  #[constructor]
+  fn new() -> Foo {
+    Foo
+  }
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use deno_bindgen::deno_bindgen;
+
+#[deno_bindgen]
+#[derive(Clone)]
+struct Inner;
+
+// Only fieldless enums can be opted in as properties.
+#[deno_bindgen]
+struct Outer {
+  #[deno_bindgen(property)]
+  pub inner: Inner,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use deno_bindgen::deno_bindgen;
+
+#[deno_bindgen]
+async fn sleep(ms: u32) -> u32 {
+  ms
+}
+
+fn main() {}
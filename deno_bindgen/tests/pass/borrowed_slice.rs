@@ -0,0 +1,28 @@
+use deno_bindgen::deno_bindgen;
+
+#[deno_bindgen]
+struct Image {
+  pixels: Vec<u8>,
+  levels: Vec<f32>,
+}
+
+#[deno_bindgen]
+impl Image {
+  #[constructor]
+  fn new() -> Image {
+    Image {
+      pixels: vec![0; 16],
+      levels: vec![1.0; 4],
+    }
+  }
+
+  fn pixels(&self) -> &[u8] {
+    &self.pixels
+  }
+
+  fn levels_mut<'a>(&'a mut self) -> &'a mut [f32] {
+    &mut self.levels
+  }
+}
+
+fn main() {}
@@ -0,0 +1,29 @@
+use deno_bindgen::deno_bindgen;
+
+#[deno_bindgen]
+struct Builder {
+  name: String,
+  size: u32,
+}
+
+#[deno_bindgen]
+impl Builder {
+  #[constructor]
+  fn new() -> Builder {
+    Builder {
+      name: String::new(),
+      size: 0,
+    }
+  }
+
+  fn size(mut self, size: u32) -> Self {
+    self.size = size;
+    self
+  }
+
+  fn build(self) -> String {
+    format!("{} ({})", self.name, self.size)
+  }
+}
+
+fn main() {}
@@ -0,0 +1,51 @@
+use deno_bindgen::deno_bindgen;
+
+/// Says hello.
+///
+/// Uses the default greeting.
+#[deno_bindgen]
+#[deprecated(since = "0.1.0", note = "use `greet_with`")]
+fn greet() -> String {
+  String::from("Hello!")
+}
+
+/// A greeting.
+#[deno_bindgen]
+#[deprecated(note = "use `Welcomer`")]
+struct Greeter {
+  greeting: String,
+}
+
+#[deno_bindgen]
+#[allow(deprecated)]
+impl Greeter {
+  /// Creates a greeter.
+  #[constructor]
+  fn new(greeting: String) -> Greeter {
+    Greeter { greeting }
+  }
+
+  /// Greets `name`.
+  #[deprecated]
+  #[inline]
+  fn greet(&self, name: &str) -> String {
+    format!("{}, {}!", self.greeting, name)
+  }
+}
+
+/// Where a greeting is sent.
+#[deno_bindgen(serde)]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Recipient {
+  name: String,
+}
+
+/// A point on the screen.
+#[deno_bindgen(repr_c)]
+#[deprecated]
+struct Point {
+  x: f64,
+  y: f64,
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+use deno_bindgen::deno_bindgen;
+
+#[deno_bindgen]
+#[repr(u32)]
+enum Mode {
+  Read = 1,
+  Write = 2,
+}
+
+#[deno_bindgen]
+enum Shape {
+  Circle { radius: f64 },
+  Rect(f64, f64),
+  Empty,
+}
+
+#[deno_bindgen]
+fn area(shape: Shape, mode: Mode) -> f64 {
+  let _ = mode as u32;
+  match shape {
+    Shape::Circle { radius } => std::f64::consts::PI * radius * radius,
+    Shape::Rect(w, h) => w * h,
+    Shape::Empty => 0.0,
+  }
+}
+
+#[deno_bindgen]
+fn default_mode() -> Mode {
+  Mode::Read
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+use deno_bindgen::deno_bindgen;
+
+#[deno_bindgen(finalize)]
+struct Session {
+  id: u32,
+}
+
+#[deno_bindgen]
+impl Session {
+  #[constructor]
+  fn new(id: u32) -> Session {
+    Session { id }
+  }
+
+  fn id(&self) -> u32 {
+    self.id
+  }
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+use deno_bindgen::deno_bindgen;
+
+#[deno_bindgen(int64 = "bigint")]
+fn timestamp() -> u64 {
+  0
+}
+
+#[deno_bindgen(int64 = "number")]
+fn offset(value: i64) -> Option<i64> {
+  value.checked_neg()
+}
+
+#[deno_bindgen]
+struct Clock {
+  now: u64,
+}
+
+#[deno_bindgen]
+impl Clock {
+  #[constructor]
+  fn new() -> Clock {
+    Clock { now: 0 }
+  }
+
+  #[deno_bindgen(int64 = "bigint")]
+  fn advance(&mut self, by: u64) -> u64 {
+    self.now += by;
+    self.now
+  }
+}
+
+fn main() {}
@@ -0,0 +1,59 @@
+use deno_bindgen::deno_bindgen;
+
+#[deno_bindgen]
+#[repr(u8)]
+#[derive(Clone, Copy)]
+enum Mode {
+  Read,
+  Write,
+}
+
+struct Connection {
+  id: u32,
+}
+
+#[deno_bindgen]
+struct Inner;
+
+#[deno_bindgen]
+struct File {
+  pub path: String,
+  pub size: u64,
+  #[deno_bindgen(property)]
+  pub mode: Mode,
+  // Neither numbers, strings nor enums, these stay private.
+  pub hint: Option<u32>,
+  pub chunks: Vec<u8>,
+  pub conn: Connection,
+  pub inner: Inner,
+  #[deno_bindgen(skip)]
+  pub fd: i32,
+  offset: u64,
+}
+
+#[deno_bindgen]
+impl File {
+  #[constructor]
+  fn new(path: String) -> File {
+    File {
+      path,
+      size: 0,
+      mode: Mode::Read,
+      hint: None,
+      chunks: Vec::new(),
+      conn: Connection { id: 0 },
+      inner: Inner,
+      fd: -1,
+      offset: 0,
+    }
+  }
+
+  fn seek(&mut self, offset: u64) {
+    self.offset = offset;
+  }
+}
+
+fn main() {
+  let _ = Mode::Write;
+  let _ = Connection { id: 1 }.id;
+}
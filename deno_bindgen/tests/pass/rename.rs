@@ -0,0 +1,70 @@
+use deno_bindgen::deno_bindgen;
+
+#[deno_bindgen(rename = "claimInterface")]
+fn claim_interface(number: u8) -> u8 {
+  number
+}
+
+#[deno_bindgen(rename = "UsbDevice")]
+struct Device {
+  pub vendor_id: u16,
+}
+
+#[deno_bindgen]
+impl Device {
+  #[constructor]
+  fn new(vendor_id: u16) -> Device {
+    Device { vendor_id }
+  }
+
+  #[deno_bindgen(rename = "selectAlternateInterface")]
+  fn select_alternate_interface(&mut self, setting: u8) -> u8 {
+    setting
+  }
+}
+
+#[deno_bindgen(serde, rename = "DeviceInfo")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Info {
+  product: String,
+}
+
+#[deno_bindgen]
+fn info(device: &Device) -> Info {
+  Info {
+    product: format!("{:04x}", device.vendor_id),
+  }
+}
+
+#[deno_bindgen(repr_c, rename = "Setting")]
+#[repr(C)]
+struct AltSetting {
+  number: u8,
+}
+
+#[deno_bindgen(rename = "Direction")]
+#[repr(u8)]
+enum UsbDirection {
+  In,
+  Out,
+}
+
+#[deno_bindgen(rename = "Transfer")]
+enum UsbTransfer {
+  Control { request: u8 },
+  Bulk(u32),
+}
+
+#[deno_bindgen]
+fn transfer(
+  setting: AltSetting,
+  direction: UsbDirection,
+  kind: UsbTransfer,
+) -> u8 {
+  match (direction, kind) {
+    (UsbDirection::In, UsbTransfer::Control { request }) => request,
+    _ => setting.number,
+  }
+}
+
+fn main() {}
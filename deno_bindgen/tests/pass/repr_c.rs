@@ -0,0 +1,39 @@
+use deno_bindgen::deno_bindgen;
+
+#[deno_bindgen(repr_c)]
+struct Vec3 {
+  x: f32,
+  y: f32,
+  z: f32,
+}
+
+#[deno_bindgen(repr_c, rename = "Flags")]
+#[repr(C)]
+struct RawFlags {
+  enabled: bool,
+  mask: u64,
+}
+
+#[deno_bindgen]
+fn scale(v: Vec3, factor: f32) -> Vec3 {
+  Vec3 {
+    x: v.x * factor,
+    y: v.y * factor,
+    z: v.z * factor,
+  }
+}
+
+#[deno_bindgen]
+fn flags(mask: u64) -> Result<RawFlags, String> {
+  Ok(RawFlags {
+    enabled: mask != 0,
+    mask,
+  })
+}
+
+#[deno_bindgen]
+fn maybe_flags(flags: Option<RawFlags>) -> Option<RawFlags> {
+  flags.filter(|flags| flags.enabled)
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+use deno_bindgen::deno_bindgen;
+use serde::Deserialize;
+use serde::Serialize;
+
+#[deno_bindgen(serde)]
+#[derive(Serialize, Deserialize)]
+struct Options {
+  #[serde(rename = "type")]
+  kind: String,
+  #[serde(skip)]
+  cache: Vec<u8>,
+}
+
+#[deno_bindgen]
+fn kind(options: Options) -> String {
+  options.kind
+}
+
+#[deno_bindgen]
+fn options(kind: &str) -> Option<Options> {
+  Some(Options {
+    kind: kind.to_string(),
+    cache: Vec::new(),
+  })
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+use deno_bindgen::deno_bindgen;
+
+#[deno_bindgen]
+struct Device {
+  id: u32,
+}
+
+#[deno_bindgen]
+impl Device {
+  fn open(id: u32) -> Self {
+    Self { id }
+  }
+
+  fn count() -> u32 {
+    0
+  }
+
+  fn find(id: u32) -> Option<Self> {
+    (id == 0).then(|| Self { id })
+  }
+
+  fn id(&self) -> u32 {
+    self.id
+  }
+}
+
+fn main() {}
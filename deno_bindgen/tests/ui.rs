@@ -9,3 +9,10 @@ fn ui_compile_fail() {
   let t = trybuild::TestCases::new();
   t.compile_fail("tests/compile_fail/*.rs");
 }
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn ui_compile_fail_without_async() {
+  let t = trybuild::TestCases::new();
+  t.compile_fail("tests/compile_fail_sync/*.rs");
+}
@@ -6,12 +6,12 @@ use std::process::Stdio;
 
 pub struct Artifact {
   pub path: PathBuf,
-  pub manifest_path: PathBuf,
 }
 
 #[derive(Default)]
 pub struct Build {
   release: bool,
+  features: Option<String>,
 }
 
 impl Build {
@@ -24,6 +24,11 @@ impl Build {
     self
   }
 
+  pub fn features(mut self, features: Option<String>) -> Self {
+    self.features = features;
+    self
+  }
+
   pub fn build(self, path: &Path) -> Result<Artifact> {
     let mut cmd = Command::new("cargo");
     cmd
@@ -37,24 +42,24 @@ impl Build {
       cmd.arg("--release");
     }
 
+    if let Some(features) = self.features {
+      cmd.arg("--features").arg(features);
+    }
+
     let status = cmd.status()?;
     let output = cmd.output()?;
     if status.success() {
       let reader = std::io::BufReader::new(output.stdout.as_slice());
       let mut artifacts = vec![];
       for message in cargo_metadata::Message::parse_stream(reader) {
-        match message.unwrap() {
-          cargo_metadata::Message::CompilerArtifact(artifact) => {
-            if artifact.target.kind.contains(&"cdylib".to_string()) {
-              artifacts.push(Artifact {
-                path: PathBuf::from(artifact.filenames[0].to_string()),
-                manifest_path: PathBuf::from(
-                  artifact.manifest_path.to_string(),
-                ),
-              });
-            }
+        if let cargo_metadata::Message::CompilerArtifact(artifact) =
+          message.unwrap()
+        {
+          if artifact.target.kind.contains(&"cdylib".to_string()) {
+            artifacts.push(Artifact {
+              path: PathBuf::from(artifact.filenames[0].to_string()),
+            });
           }
-          _ => {}
         }
       }
 
@@ -64,10 +69,7 @@ impl Build {
         return Ok(artifact);
       }
 
-      Err(std::io::Error::new(
-        std::io::ErrorKind::Other,
-        "failed to parse cargo output",
-      ))?
+      Err(std::io::Error::other("failed to parse cargo output"))?
     } else {
       println!(
         "failed to execute `cargo`: exited with {}\n  full command: {:?}",
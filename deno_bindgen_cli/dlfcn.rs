@@ -1,9 +1,18 @@
+use std::ffi::c_char;
+use std::ffi::CStr;
 use std::path::Path;
 use std::path::PathBuf;
 
+use deno_bindgen_ir::codegen::Case;
+use deno_bindgen_ir::codegen::VERSION;
 use dlopen2::wrapper::Container;
 use dlopen2::wrapper::WrapperApi;
 
+#[derive(WrapperApi)]
+struct VersionApi {
+  deno_bindgen_ir_version: unsafe extern "C" fn() -> *const c_char,
+}
+
 #[derive(WrapperApi)]
 struct Api {
   init_deno_bindgen: unsafe fn(opt: deno_bindgen_ir::codegen::Options),
@@ -13,19 +22,34 @@ pub unsafe fn load_and_init(
   path: &Path,
   out: Option<PathBuf>,
   lazy_init: bool,
+  case: Case,
 ) -> std::io::Result<()> {
-  let cont: Container<Api> = Container::load(path).map_err(|e| {
-    std::io::Error::new(
-      std::io::ErrorKind::Other,
-      format!("failed to load library: {}", e),
+  // `Options` is a plain Rust struct, its layout is only known to match when
+  // both sides use the same deno_bindgen_ir.
+  let version: Container<VersionApi> = Container::load(path).map_err(|_| {
+    std::io::Error::other(
+      "library was built with an older deno_bindgen, update it to match the CLI",
     )
   })?;
+  let expected = VERSION.trim_end_matches('\0');
+  let found =
+    CStr::from_ptr(version.deno_bindgen_ir_version()).to_string_lossy();
+  if found != expected {
+    return Err(std::io::Error::other(format!(
+      "library was built with deno_bindgen_ir {found}, but the CLI uses {expected}"
+    )));
+  }
+
+  let cont: Container<Api> = Container::load(path).map_err(|e| {
+    std::io::Error::other(format!("failed to load library: {}", e))
+  })?;
 
   cont.init_deno_bindgen(deno_bindgen_ir::codegen::Options {
     target: deno_bindgen_ir::codegen::Target::Deno,
     out,
     local_dylib_path: path.to_path_buf(),
     lazy_init,
+    case,
   });
 
   Ok(())
@@ -1,6 +1,7 @@
 use std::path::PathBuf;
 
 use cargo::Artifact;
+use deno_bindgen_ir::codegen::Case;
 use structopt::StructOpt;
 
 mod cargo;
@@ -16,28 +17,38 @@ struct Opt {
   #[structopt(short, long)]
   out: Option<PathBuf>,
 
+  #[structopt(long)]
+  /// Space or comma separated list of features to build with
+  features: Option<String>,
+
   #[structopt(short, long)]
   lazy_init: bool,
+
+  #[structopt(long, default_value = "snake")]
+  /// Naming convention of exported functions, methods and properties:
+  /// `snake` or `camel`
+  case: Case,
 }
 
 fn main() -> std::io::Result<()> {
   let opt = Opt::from_args();
 
   let cwd = std::env::current_dir().unwrap();
-  let Artifact { path, .. } =
-    cargo::Build::new().release(opt.release).build(&cwd)?;
+  let Artifact { path, .. } = cargo::Build::new()
+    .release(opt.release)
+    .features(opt.features)
+    .build(&cwd)?;
 
   let name = cargo::metadata()?;
   println!("Initializing {name}");
 
-  let path = PathBuf::from(path);
   // https://github.com/denoland/deno/issues/21172
   #[cfg(target_os = "windows")]
   let path = path
     .strip_prefix(&cwd)
     .expect("path is not a prefix of cwd");
 
-  unsafe { dlfcn::load_and_init(&path, opt.out, opt.lazy_init)? };
+  unsafe { dlfcn::load_and_init(&path, opt.out, opt.lazy_init, opt.case)? };
 
   println!("Ready {name}");
   Ok(())
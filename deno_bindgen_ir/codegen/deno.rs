@@ -3,69 +3,559 @@ use std::io::Result;
 use std::io::Write;
 use std::path::Path;
 
+use super::Case;
 use super::Generator;
+use crate::inventory::CStruct;
+use crate::inventory::Enum;
+use crate::inventory::Interface;
 use crate::inventory::Inventory;
+use crate::inventory::Properties;
+use crate::inventory::Property;
 use crate::inventory::Struct;
+use crate::inventory::Union;
+use crate::Int64Mode;
 use crate::Type;
 
-// (ident, is_custom_type)
-struct TypeScriptType<'a>(&'a str, bool);
+/// A type as seen from TypeScript. Custom types are looked up in the
+/// inventory, as they are either classes, enums or passed as JSON.
+#[derive(Clone, Copy)]
+struct TypeScriptType<'a>(Type, &'a [Inventory], Int64Mode);
 
 impl std::fmt::Display for TypeScriptType<'_> {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    f.write_str(&self.0)?;
-    Ok(())
+    let ty = match self.0 {
+      Type::Void => "void",
+      Type::Bool => "boolean",
+      Type::Uint64 | Type::Int64 | Type::Usize | Type::Isize => match self.2 {
+        Int64Mode::Auto => "number | bigint",
+        Int64Mode::BigInt => "bigint",
+        Int64Mode::Number => "number",
+      },
+      Type::Uint8
+      | Type::Uint16
+      | Type::Uint32
+      | Type::Int8
+      | Type::Int16
+      | Type::Int32
+      | Type::Float32
+      | Type::Float64 => "number",
+      Type::Pointer => "Deno.PointerObject | null",
+      Type::Buffer(ty) | Type::Vec(ty) => typed_array(*ty),
+      Type::Str | Type::String => "string",
+      Type::CustomType(name) => class_name(self.1, name),
+      Type::Result(ty) => return self.with(*ty).fmt(f),
+      Type::Optional(ty) => return write!(f, "{} | null", self.with(*ty)),
+      // Callbacks kept by Rust are created and closed by the caller.
+      Type::Function(callback) if callback.stored => "Deno.UnsafeCallback",
+      Type::Function(callback) => {
+        // Callbacks are called by Deno, which doesn't convert integers.
+        let this = self.int64(Int64Mode::Auto);
+        let parameters = callback
+          .parameters
+          .iter()
+          .enumerate()
+          .map(|(i, ty)| format!("arg{i}: {}", this.with(*ty)))
+          .collect::<Vec<_>>()
+          .join(", ");
+        // Anything else is only valid during the call.
+        return write!(
+          f,
+          "(({parameters}) => {}) | Deno.UnsafeCallback",
+          this.with(callback.result)
+        );
+      }
+    };
+    f.write_str(ty)
+  }
+}
+
+/// The typed array holding elements of a number type.
+fn typed_array(ty: Type) -> &'static str {
+  match ty {
+    Type::Uint8 => "Uint8Array",
+    Type::Uint16 => "Uint16Array",
+    Type::Uint32 => "Uint32Array",
+    Type::Uint64 => "BigUint64Array",
+    Type::Int8 => "Int8Array",
+    Type::Int16 => "Int16Array",
+    Type::Int32 => "Int32Array",
+    Type::Int64 => "BigInt64Array",
+    Type::Float32 => "Float32Array",
+    Type::Float64 => "Float64Array",
+    _ => unreachable!("not a number type"),
+  }
+}
+
+/// The pointer width of the target. Bindings are generated from within the
+/// library, so this is the width the library was built for.
+const POINTER_SIZE: usize = std::mem::size_of::<usize>();
+
+/// Integers that Deno hands out as `number | bigint`.
+fn is_int64(ty: Type) -> bool {
+  matches!(ty, Type::Uint64 | Type::Int64 | Type::Usize | Type::Isize)
+}
+
+/// The `DataView` getter and byte size of a number type.
+fn data_view_getter(ty: Type) -> (&'static str, usize) {
+  match ty {
+    Type::Bool => ("getUint8", 1),
+    Type::Uint8 => ("getUint8", 1),
+    Type::Uint16 => ("getUint16", 2),
+    Type::Uint32 => ("getUint32", 4),
+    Type::Uint64 => ("getBigUint64", 8),
+    Type::Int8 => ("getInt8", 1),
+    Type::Int16 => ("getInt16", 2),
+    Type::Int32 => ("getInt32", 4),
+    Type::Int64 => ("getBigInt64", 8),
+    Type::Usize if POINTER_SIZE == 8 => ("getBigUint64", 8),
+    Type::Usize => ("getUint32", 4),
+    Type::Isize if POINTER_SIZE == 8 => ("getBigInt64", 8),
+    Type::Isize => ("getInt32", 4),
+    Type::Float32 => ("getFloat32", 4),
+    Type::Float64 => ("getFloat64", 8),
+    _ => unreachable!("not a number type"),
+  }
+}
+
+/// The alignment of a number type, like [`POINTER_SIZE`] it is the one of
+/// the library's target. 8-byte numbers are 4-byte aligned on most 32-bit
+/// targets.
+fn align_of(ty: Type) -> usize {
+  use std::mem::align_of;
+
+  match ty {
+    Type::Bool | Type::Uint8 | Type::Int8 => 1,
+    Type::Uint16 | Type::Int16 => align_of::<u16>(),
+    Type::Uint32 | Type::Int32 => align_of::<u32>(),
+    Type::Uint64 | Type::Int64 => align_of::<u64>(),
+    Type::Usize | Type::Isize => align_of::<usize>(),
+    Type::Float32 => align_of::<f32>(),
+    Type::Float64 => align_of::<f64>(),
+    _ => unreachable!("not a number type"),
+  }
+}
+
+/// The trailing `littleEndian` argument of a `DataView` accessor, which
+/// single bytes don't take.
+fn little_endian(size: usize) -> &'static str {
+  if size == 1 {
+    ""
+  } else {
+    ", true"
+  }
+}
+
+/// The inventory entry of a custom type that isn't a class.
+fn lookup(symbols: &[Inventory], ty: Type) -> Option<&Inventory> {
+  let Type::CustomType(name) = ty else {
+    return None;
+  };
+  symbols.iter().find(|symbol| match symbol {
+    Inventory::Interface(Interface { name: other, .. })
+    | Inventory::CStruct(CStruct { name: other, .. })
+    | Inventory::Enum(Enum { name: other, .. })
+    | Inventory::Union(Union { name: other, .. }) => *other == name,
+    _ => false,
+  })
+}
+
+/// The TypeScript name of a class or interface, which may be renamed.
+fn class_name<'a>(symbols: &'a [Inventory], name: &'a str) -> &'a str {
+  symbols
+    .iter()
+    .find_map(|symbol| match symbol {
+      Inventory::Struct(Struct {
+        name: other,
+        rename,
+        ..
+      })
+      | Inventory::Interface(Interface {
+        name: other,
+        rename,
+        ..
+      })
+      | Inventory::CStruct(CStruct {
+        name: other,
+        rename,
+        ..
+      })
+      | Inventory::Enum(Enum {
+        name: other,
+        rename,
+        ..
+      })
+      | Inventory::Union(Union {
+        name: other,
+        rename,
+        ..
+      }) if *other == name => *rename,
+      _ => None,
+    })
+    .unwrap_or(name)
+}
+
+fn is_json(symbols: &[Inventory], ty: Type) -> bool {
+  matches!(
+    lookup(symbols, ty),
+    Some(Inventory::Interface(_) | Inventory::Union(_))
+  )
+}
+
+fn c_struct(symbols: &[Inventory], ty: Type) -> Option<&CStruct> {
+  match lookup(symbols, ty) {
+    Some(Inventory::CStruct(c_struct)) => Some(c_struct),
+    _ => None,
+  }
+}
+
+/// Object fields, quoted unless they are identifiers or indices.
+fn property(name: &str) -> Cow<'_, str> {
+  let is_ident = !name.starts_with(|c: char| c.is_ascii_digit())
+    && name
+      .chars()
+      .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+  let is_index = !name.is_empty() && name.chars().all(|c| c.is_ascii_digit());
+  if is_ident || is_index {
+    Cow::Borrowed(name)
+  } else {
+    Cow::Owned(format!("{name:?}"))
+  }
+}
+
+/// Parameter names, suffixed with `_` when they are reserved in JavaScript or
+/// would shadow a binding used by the generated code.
+fn parameter(name: &str) -> Cow<'_, str> {
+  const RESERVED: &[&str] = &[
+    "arguments",
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "eval",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "instanceof",
+    "interface",
+    "let",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "static",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+    // Bindings of the generated code.
+    "ret",
+    "symbols",
+    "__error",
+    "__encoder",
+    "__decoder",
+    "__readBuffer",
+    "__checkError",
+  ];
+  if RESERVED.contains(&name) {
+    Cow::Owned(format!("{name}_"))
+  } else {
+    Cow::Borrowed(name)
+  }
+}
+
+/// A JSDoc block for `docs`, indented by `indent`. Nothing is written for
+/// undocumented items.
+fn jsdoc<W: Write>(
+  writer: &mut W,
+  indent: &str,
+  docs: &str,
+  deprecated: Option<&str>,
+) -> Result<()> {
+  if docs.is_empty() && deprecated.is_none() {
+    return Ok(());
+  }
+
+  let mut lines = docs.lines().map(str::to_string).collect::<Vec<_>>();
+  if let Some(note) = deprecated {
+    if !lines.is_empty() {
+      lines.push(String::new());
+    }
+    lines.push(format!("@deprecated {note}").trim_end().to_string());
+  }
+
+  writeln!(writer, "{indent}/**")?;
+  for line in lines {
+    // Don't let the docs close the comment early.
+    let line = line.replace("*/", "*\\/");
+    if line.is_empty() {
+      writeln!(writer, "{indent} *")?;
+    } else {
+      writeln!(writer, "{indent} * {line}")?;
+    }
+  }
+  writeln!(writer, "{indent} */")?;
+  Ok(())
+}
+
+fn enum_repr(symbols: &[Inventory], ty: Type) -> Option<Type> {
+  match lookup(symbols, ty) {
+    Some(Inventory::Enum(Enum { repr, .. })) => Some(*repr),
+    _ => None,
   }
 }
 
 impl TypeScriptType<'_> {
-  fn into_raw<'a>(&self, ident: &'a str) -> Cow<'a, str> {
-    match self {
-      Self("Uint8Array", false) => {
+  fn with(self, ty: Type) -> Self {
+    Self(ty, self.1, self.2)
+  }
+
+  fn int64(self, mode: Int64Mode) -> Self {
+    Self(self.0, self.1, mode)
+  }
+
+  fn is_json(self) -> bool {
+    is_json(self.1, self.0)
+  }
+
+  fn is_enum(self) -> bool {
+    enum_repr(self.1, self.0).is_some()
+  }
+
+  fn is_c_struct(self) -> bool {
+    c_struct(self.1, self.0).is_some()
+  }
+
+  /// Statements that prepare `ident` before it is passed to the symbol.
+  fn prelude(self, ident: &str, non_blocking: bool) -> Option<String> {
+    match self.0 {
+      // A closed callback would be called once Rust uses it.
+      Type::Function(callback) if callback.stored => Some(format!(
+        "if (!({ident} instanceof Deno.UnsafeCallback)) {{\n    throw new TypeError(\"{ident} is kept after the call, pass a Deno.UnsafeCallback\");\n  }}"
+      )),
+      Type::Function(callback) => {
+        let parameters = callback
+          .parameters
+          .iter()
+          .map(|ty| DenoFfiType::from(*ty).to_string())
+          .collect::<Vec<_>>()
+          .join(", ");
+        let definition = format!(
+          "{{ parameters: [{parameters}], result: {} }}",
+          DenoFfiType::from(callback.result)
+        );
+        // Non-blocking calls run on another thread.
+        let constructor = if non_blocking {
+          "Deno.UnsafeCallback.threadSafe"
+        } else {
+          "new Deno.UnsafeCallback"
+        };
+        Some(format!(
+          "const {ident}Callback = {ident} instanceof Deno.UnsafeCallback\n    ? {ident}\n    : {constructor}({definition}, {ident});"
+        ))
+      }
+      // NUL-terminated, `JSON.stringify` escapes control characters.
+      _ if self.is_json() => Some(format!(
+        "const {ident}Buf = __encoder.encode(JSON.stringify({ident}) + \"\\0\");"
+      )),
+      Type::Optional(ty) if self.with(*ty).is_json() => Some(format!(
+        "const {ident}Buf = {ident} == null ? null : __encoder.encode(JSON.stringify({ident}) + \"\\0\");"
+      )),
+      Type::Str | Type::String => {
+        Some(format!("const {ident}Buf = __encoder.encode({ident});"))
+      }
+      Type::Optional(Type::Str | Type::String) => Some(format!(
+        "const {ident}Buf = {ident} == null ? null : __encoder.encode({ident});"
+      )),
+      _ => None,
+    }
+  }
+
+  fn into_raw(self, ident: &str) -> Cow<'_, str> {
+    match self.0 {
+      Type::Function(callback) if callback.stored => {
+        Cow::Owned(format!("{ident}.pointer"))
+      }
+      Type::Function(_) => Cow::Owned(format!("{ident}Callback.pointer")),
+      _ if self.is_json() => {
+        Cow::Owned(format!("Deno.UnsafePointer.of({ident}Buf)"))
+      }
+      Type::Optional(ty) if self.with(*ty).is_json() => Cow::Owned(format!(
+        "{ident}Buf === null ? 0 : 1,\n    {ident}Buf === null ? null : Deno.UnsafePointer.of({ident}Buf)"
+      )),
+      _ if self.is_enum() => Cow::Borrowed(ident),
+      Type::CustomType(name) if self.is_c_struct() => {
+        Cow::Owned(format!("pack{}({ident})", class_name(self.1, name)))
+      }
+      Type::Buffer(_) => {
         Cow::Owned(format!("{ident},\n    {ident}.byteLength"))
       }
-      Self(_, true) => Cow::Owned(format!("{ident}.ptr")),
+      Type::Str | Type::String => {
+        Cow::Owned(format!("{ident}Buf,\n    {ident}Buf.byteLength"))
+      }
+      Type::CustomType(_) => Cow::Owned(format!("__handle({ident})")),
+      Type::Optional(ty @ Type::CustomType(name))
+        if self.with(*ty).is_c_struct() =>
+      {
+        let size = c_struct(self.1, *ty).map_or(0, |c| c.size);
+        Cow::Owned(format!(
+          "{ident} == null ? 0 : 1,\n    {ident} == null ? new Uint8Array({size}) : pack{}({ident})",
+          class_name(self.1, name)
+        ))
+      }
+      Type::Optional(ty @ Type::CustomType(_)) if !self.with(*ty).is_enum() => {
+        Cow::Owned(format!(
+          "{ident} == null ? 0 : 1,\n    {ident} == null ? null : __handle({ident})"
+        ))
+      }
+      Type::Optional(Type::Buffer(_)) => Cow::Owned(format!(
+        "{ident} == null ? 0 : 1,\n    {ident} ?? null,\n    {ident}?.byteLength ?? 0"
+      )),
+      Type::Optional(Type::Str | Type::String) => Cow::Owned(format!(
+        "{ident}Buf === null ? 0 : 1,\n    {ident}Buf,\n    {ident}Buf?.byteLength ?? 0"
+      )),
+      Type::Optional(Type::Bool) => Cow::Owned(format!(
+        "{ident} == null ? 0 : 1,\n    {ident} ?? false"
+      )),
+      Type::Optional(_) => {
+        Cow::Owned(format!("{ident} == null ? 0 : 1,\n    {ident} ?? 0"))
+      }
       _ => Cow::Borrowed(ident),
     }
   }
 
-  fn from_raw<'a>(&self, ident: &'a str) -> Option<String> {
-    match self {
-      Self(ty_str, true) => Some(format!("{ty_str}.__constructor({ident})")),
+  /// Statements that release what the prelude allocated, once the call
+  /// returns.
+  fn cleanup(self, ident: &str) -> Option<String> {
+    match self.0 {
+      Type::Function(callback) if !callback.stored => Some(format!(
+        "if ({ident}Callback !== {ident}) {ident}Callback.close();"
+      )),
       _ => None,
     }
   }
 
-  fn apply_promise(&self, non_blocking: bool) -> Cow<'_, str> {
-    if non_blocking {
-      Cow::Owned(format!("Promise<{}>", self.0))
-    } else {
-      Cow::Borrowed(self.0)
+  fn ret_from_raw(self, ident: &str) -> Option<String> {
+    match self.0 {
+      _ if self.is_json() => Some(format!(
+        "JSON.parse(__decoder.decode(__readBuffer({ident})))"
+      )),
+      _ if self.is_enum() => None,
+      Type::CustomType(name) if self.is_c_struct() => {
+        Some(format!("unpack{}({ident})", class_name(self.1, name)))
+      }
+      Type::CustomType(name) => Some(format!(
+        "{}.__constructor({ident})",
+        class_name(self.1, name)
+      )),
+      Type::String => Some(format!("__decoder.decode(__readBuffer({ident}))")),
+      Type::Vec(Type::Uint8) => Some(format!("__readBuffer({ident})")),
+      Type::Buffer(ty) => {
+        Some(format!("new {}(__viewSlice({ident}))", typed_array(*ty)))
+      }
+      // The copy is a fresh `ArrayBuffer`, aligned for any element type.
+      Type::Vec(ty) => Some(format!(
+        "new {}(__readBuffer({ident}).buffer)",
+        typed_array(*ty)
+      )),
+      Type::Uint64 | Type::Int64 | Type::Usize | Type::Isize => match self.2 {
+        Int64Mode::Auto => None,
+        Int64Mode::BigInt => Some(format!("BigInt({ident})")),
+        Int64Mode::Number => Some(format!("__toNumber({ident})")),
+      },
+      Type::Result(ty) => self.with(*ty).ret_from_raw(ident),
+      // The value follows the flag, aligned.
+      Type::Optional(ty) if ty.is_scalar() => {
+        let (getter, size) = data_view_getter(*ty);
+        let mut value = format!(
+          "new DataView({ident}.buffer, {ident}.byteOffset).{getter}({}{})",
+          align_of(*ty),
+          little_endian(size)
+        );
+        // Read as a `bigint`, narrowed the way Deno does for plain values.
+        // Pointer-sized integers are read as numbers on 32-bit targets.
+        if is_int64(*ty) {
+          value = match (self.2, size) {
+            (Int64Mode::Auto, 8) => format!("__toInt64({value})"),
+            (Int64Mode::Number, 8) => format!("__toNumber({value})"),
+            (Int64Mode::BigInt, 4) => format!("BigInt({value})"),
+            _ => value,
+          };
+        }
+        if *ty == Type::Bool {
+          value = format!("{value} !== 0");
+        }
+        Some(format!("{ident}[0] === 0 ? null : {value}"))
+      }
+      Type::Optional(ty) if self.with(*ty).is_enum() => {
+        let repr = enum_repr(self.1, *ty)?;
+        self
+          .with(Type::Optional(Box::leak(Box::new(repr))))
+          .ret_from_raw(ident)
+      }
+      // The struct follows the flag, aligned.
+      Type::Optional(ty @ Type::CustomType(name))
+        if self.with(*ty).is_c_struct() =>
+      {
+        let align = c_struct(self.1, *ty)?.align;
+        Some(format!(
+          "{ident}[0] === 0 ? null : unpack{}({ident}.subarray({align}))",
+          class_name(self.1, name)
+        ))
+      }
+      // A `deno_bindgen::Optional` of a handle.
+      Type::Optional(ty @ Type::CustomType(_)) => {
+        let view = format!("new DataView({ident}.buffer, {ident}.byteOffset)");
+        let ptr = if POINTER_SIZE == 8 {
+          format!("Deno.UnsafePointer.create({view}.getBigUint64(8, true))")
+        } else {
+          format!(
+            "Deno.UnsafePointer.create(BigInt({view}.getUint32(4, true)))"
+          )
+        };
+        let value = self.with(*ty).ret_from_raw(&ptr)?;
+        Some(format!("{ident}[0] === 0 ? null : {value}"))
+      }
+      Type::Optional(ty) => {
+        let value = self.with(*ty).ret_from_raw(ident)?;
+        Some(format!("{ident} === null ? null : {value}"))
+      }
+      _ => None,
     }
   }
-}
 
-impl From<Type> for TypeScriptType<'_> {
-  fn from(value: Type) -> Self {
-    Self(
-      match value {
-        Type::Void => "void",
-        Type::Uint8
-        | Type::Uint16
-        | Type::Uint32
-        | Type::Uint64
-        | Type::Int8
-        | Type::Int16
-        | Type::Int32
-        | Type::Int64
-        | Type::Float32
-        | Type::Float64 => "number",
-        Type::Pointer => "Deno.PointerObject | null",
-        Type::Buffer => "Uint8Array",
-        Type::CustomType(name) => name,
-      },
-      matches!(value, Type::CustomType(_)),
-    )
+  fn apply_promise(self, non_blocking: bool) -> String {
+    if non_blocking {
+      format!("Promise<{}>", self)
+    } else {
+      self.to_string()
+    }
   }
 }
 
@@ -78,10 +568,31 @@ impl std::fmt::Display for DenoFfiType {
   }
 }
 
+impl DenoFfiType {
+  fn result(value: Type) -> Self {
+    match value {
+      // Owned values are handed over behind a pointer.
+      Type::String | Type::Vec(_) => Self("'pointer'".to_string()),
+      // A `deno_bindgen::BorrowedSlice`.
+      Type::Buffer(_) => Self("{ struct: ['pointer', 'usize'] }".to_string()),
+      // Errors are reported through a trailing out parameter.
+      Type::Result(ty) => Self::result(*ty),
+      Type::Optional(ty)
+        if ty.is_scalar() || matches!(ty, Type::CustomType(_)) =>
+      {
+        Self(format!("{{ struct: ['u8', {}] }}", Self::from(*ty)))
+      }
+      Type::Optional(_) => Self("'pointer'".to_string()),
+      _ => Self::from(value),
+    }
+  }
+}
+
 impl From<Type> for DenoFfiType {
   fn from(value: Type) -> Self {
     let ty = match value {
       Type::Void => "void",
+      Type::Bool => "bool",
       Type::Uint8 => "u8",
       Type::Uint16 => "u16",
       Type::Uint32 => "u32",
@@ -90,16 +601,25 @@ impl From<Type> for DenoFfiType {
       Type::Int16 => "i16",
       Type::Int32 => "i32",
       Type::Int64 => "i64",
+      Type::Usize => "usize",
+      Type::Isize => "isize",
       Type::Float32 => "f32",
       Type::Float64 => "f64",
       Type::CustomType(..) | Type::Pointer => "pointer",
-      Type::Buffer => "buffer",
+      Type::Buffer(_) | Type::Str | Type::String => "buffer",
+      Type::Function(_) => "function",
+      Type::Result(_) => unreachable!("results are only returned"),
+      Type::Vec(_) => unreachable!("vectors are only returned"),
+      // Prefixed with a flag telling whether the value is present.
+      Type::Optional(ty) => {
+        return Self(format!("'u8',\n      {}", Self::from(*ty)))
+      }
     };
 
     let mut raw = format!("'{}'", ty);
 
     // Complex types.
-    if value == Type::Buffer {
+    if matches!(value, Type::Buffer(_) | Type::Str | Type::String) {
       raw.push_str(",\n      'usize'");
     }
 
@@ -107,18 +627,41 @@ impl From<Type> for DenoFfiType {
   }
 }
 
+fn contains(ty: Type, f: &impl Fn(Type) -> bool) -> bool {
+  f(ty)
+    || match ty {
+      Type::Result(ty) | Type::Optional(ty) => contains(*ty, f),
+      _ => false,
+    }
+}
+
 pub struct Codegen<'a> {
   symbols: &'a [Inventory],
   target: &'a Path,
   lazy: bool,
+  case: Case,
 }
 
 impl<'a> Codegen<'a> {
-  pub fn new(symbols: &'a [Inventory], target: &'a Path, lazy: bool) -> Self {
+  pub fn new(
+    symbols: &'a [Inventory],
+    target: &'a Path,
+    lazy: bool,
+    case: Case,
+  ) -> Self {
     Self {
       symbols,
       target,
       lazy,
+      case,
+    }
+  }
+
+  /// The exported name of a function, method or property.
+  fn export_name(&self, name: &str, rename: Option<&'a str>) -> Cow<'a, str> {
+    match rename {
+      Some(rename) => Cow::Borrowed(rename),
+      None => Cow::Owned(self.case.apply(name)),
     }
   }
 
@@ -134,6 +677,260 @@ impl<'a> Codegen<'a> {
     Ok(())
   }
 
+  fn ts(&self, ty: Type) -> TypeScriptType<'a> {
+    TypeScriptType(ty, self.symbols, Int64Mode::Auto)
+  }
+
+  /// The type as seen by `Deno.dlopen`. Enums are passed as their `#[repr]`
+  /// integer.
+  fn ffi_type(&self, ty: Type) -> Type {
+    match ty {
+      Type::Result(inner) | Type::Optional(inner) => {
+        let raw = self.ffi_type(*inner);
+        if raw == *inner {
+          return ty;
+        }
+        let raw = Box::leak(Box::new(raw));
+        match ty {
+          Type::Result(_) => Type::Result(raw),
+          _ => Type::Optional(raw),
+        }
+      }
+      _ => enum_repr(self.symbols, ty).unwrap_or(ty),
+    }
+  }
+
+  /// `repr_c` structs are passed as Deno FFI structs of their fields.
+  /// Options of them are prefixed with a presence flag, as a separate
+  /// parameter or as the first field of the returned struct.
+  fn struct_ffi_type(&self, ty: Type, result: bool) -> Option<DenoFfiType> {
+    let ty = match ty {
+      Type::Result(ty) => *ty,
+      ty => ty,
+    };
+    let (ty, optional) = match ty {
+      Type::Optional(ty) => (*ty, true),
+      ty => (ty, false),
+    };
+    let fields = c_struct(self.symbols, ty)?
+      .fields
+      .iter()
+      .map(|field| DenoFfiType::from(field.ty).to_string())
+      .collect::<Vec<_>>()
+      .join(", ");
+    let raw = format!("{{ struct: [{fields}] }}");
+    Some(DenoFfiType(match (optional, result) {
+      (false, _) => raw,
+      (true, false) => format!("'u8',\n      {raw}"),
+      (true, true) => format!("{{ struct: ['u8', {raw}] }}"),
+    }))
+  }
+
+  /// The `pub` fields of a class.
+  fn properties(&self, class: &'a str) -> impl Iterator<Item = &'a Property> {
+    self
+      .symbols
+      .iter()
+      .filter_map(move |symbol| match symbol {
+        Inventory::Properties(Properties { name, properties })
+          if *name == class =>
+        {
+          Some(properties.iter())
+        }
+        _ => None,
+      })
+      .flatten()
+  }
+
+  /// Whether any symbol takes a parameter containing a type matching `f`.
+  fn takes(&self, f: impl Fn(Type) -> bool) -> bool {
+    self.symbols.iter().any(|symbol| match symbol {
+      Inventory::Symbol(symbol) => {
+        symbol.parameters.iter().any(|param| contains(param.ty, &f))
+      }
+      _ => false,
+    })
+  }
+
+  /// Whether any symbol returns a type matching `f`.
+  fn returns(&self, f: impl Fn(Type) -> bool) -> bool {
+    self.symbols.iter().any(|symbol| match symbol {
+      Inventory::Symbol(symbol) => contains(symbol.return_type, &f),
+      _ => false,
+    })
+  }
+
+  /// Whether there are any symbols, which may all hand over a
+  /// `deno_bindgen::OwnedBuffer` holding an error.
+  fn has_symbols(&self) -> bool {
+    self
+      .symbols
+      .iter()
+      .any(|symbol| matches!(symbol, Inventory::Symbol(_)))
+  }
+
+  fn runtime<W: Write>(&self, writer: &mut W) -> Result<()> {
+    if self.takes(|ty| {
+      matches!(ty, Type::Str | Type::String) || is_json(self.symbols, ty)
+    }) {
+      writeln!(writer, "const __encoder = new TextEncoder();\n")?;
+    }
+
+    if self.has_symbols() {
+      writeln!(writer, "const __decoder = new TextDecoder();\n")?;
+
+      // Copies out a `deno_bindgen::OwnedBuffer` and releases it.
+      writeln!(
+        writer,
+        "function __readBuffer(ptr: Deno.PointerObject | null): Uint8Array {{"
+      )?;
+      writeln!(writer, "  const view = new Deno.UnsafePointerView(ptr!);")?;
+      // `OwnedBuffer` is a pointer followed by its length.
+      if POINTER_SIZE == 8 {
+        writeln!(writer, "  const len = Number(view.getBigUint64(8));")?;
+      } else {
+        writeln!(writer, "  const len = view.getUint32(4);")?;
+      }
+      writeln!(writer, "  const buf = new Uint8Array(len);")?;
+      writeln!(writer, "  if (len > 0) {{")?;
+      writeln!(
+        writer,
+        "    Deno.UnsafePointerView.copyInto(view.getPointer()!, buf);"
+      )?;
+      writeln!(writer, "  }}")?;
+      writeln!(writer, "  symbols.__deno_bindgen_free(ptr);")?;
+      writeln!(writer, "  return buf;")?;
+      writeln!(writer, "}}\n")?;
+
+      // Error slot shared by blocking calls, see `Type::error_slot`.
+      writeln!(writer, "const __error = new BigUint64Array(1);\n")?;
+
+      writeln!(writer, "function __checkError(slot: BigUint64Array) {{")?;
+      writeln!(writer, "  if (slot[0] === 0n) {{")?;
+      writeln!(writer, "    return;")?;
+      writeln!(writer, "  }}")?;
+      writeln!(writer, "  const ptr = Deno.UnsafePointer.create(slot[0]);")?;
+      writeln!(writer, "  slot[0] = 0n;")?;
+      writeln!(
+        writer,
+        "  throw new Error(__decoder.decode(__readBuffer(ptr)));"
+      )?;
+      writeln!(writer, "}}\n")?;
+    }
+
+    // Narrowing of 64-bit integers, see `Int64Mode`.
+    let returns_int64 = |mode: Int64Mode, f: fn(Type) -> bool| {
+      self.symbols.iter().any(|symbol| match symbol {
+        Inventory::Symbol(symbol) => {
+          symbol.int64 == mode && contains(symbol.return_type, &f)
+        }
+        _ => false,
+      })
+    };
+    if returns_int64(Int64Mode::Number, is_int64) {
+      writeln!(
+        writer,
+        "function __toNumber(value: number | bigint): number {{"
+      )?;
+      writeln!(writer, "  if (typeof value === \"number\") {{")?;
+      writeln!(writer, "    return value;")?;
+      writeln!(writer, "  }}")?;
+      writeln!(
+        writer,
+        "  if (value > BigInt(Number.MAX_SAFE_INTEGER) || value < BigInt(Number.MIN_SAFE_INTEGER)) {{"
+      )?;
+      writeln!(
+        writer,
+        "    throw new RangeError(`${{value}} is not a safe integer`);"
+      )?;
+      writeln!(writer, "  }}")?;
+      writeln!(writer, "  return Number(value);")?;
+      writeln!(writer, "}}\n")?;
+    }
+    if returns_int64(
+      Int64Mode::Auto,
+      |ty| matches!(ty, Type::Optional(ty) if is_int64(*ty)),
+    ) {
+      writeln!(
+        writer,
+        "function __toInt64(value: bigint): number | bigint {{"
+      )?;
+      writeln!(
+        writer,
+        "  return value > BigInt(Number.MAX_SAFE_INTEGER) || value < BigInt(Number.MIN_SAFE_INTEGER)"
+      )?;
+      writeln!(writer, "    ? value")?;
+      writeln!(writer, "    : Number(value);")?;
+      writeln!(writer, "}}\n")?;
+    }
+
+    if self
+      .symbols
+      .iter()
+      .any(|symbol| matches!(symbol, Inventory::Struct(_)))
+    {
+      // Handles are cleared when the object is moved into Rust, see
+      // `Symbol::consumes_self`.
+      writeln!(
+        writer,
+        "function __handle(self: {{ ptr: Deno.PointerObject | null }}): Deno.PointerObject {{"
+      )?;
+      writeln!(writer, "  if (self.ptr === null) {{")?;
+      writeln!(
+        writer,
+        "    throw new Error(\"object has been consumed or disposed\");"
+      )?;
+      writeln!(writer, "  }}")?;
+      writeln!(writer, "  return self.ptr;")?;
+      writeln!(writer, "}}\n")?;
+    }
+
+    if self.returns(|ty| matches!(ty, Type::Buffer(_))) {
+      // Views a `deno_bindgen::BorrowedSlice` in place.
+      writeln!(
+        writer,
+        "function __viewSlice(slice: Uint8Array): ArrayBuffer {{"
+      )?;
+      writeln!(
+        writer,
+        "  const view = new DataView(slice.buffer, slice.byteOffset);"
+      )?;
+      if POINTER_SIZE == 8 {
+        writeln!(
+          writer,
+          "  const ptr = Deno.UnsafePointer.create(view.getBigUint64(0, true));"
+        )?;
+        writeln!(writer, "  const len = Number(view.getBigUint64(8, true));")?;
+      } else {
+        writeln!(
+          writer,
+          "  const ptr = Deno.UnsafePointer.create(BigInt(view.getUint32(0, true)));"
+        )?;
+        writeln!(writer, "  const len = view.getUint32(4, true);")?;
+      }
+      writeln!(writer, "  return ptr === null || len === 0")?;
+      writeln!(writer, "    ? new ArrayBuffer(0)")?;
+      writeln!(
+        writer,
+        "    : Deno.UnsafePointerView.getArrayBuffer(ptr, len);"
+      )?;
+      writeln!(writer, "}}\n")?;
+
+      // Views keep the object they borrow from alive, so that it is not
+      // finalized while they are in use.
+      writeln!(writer, "const __owners = new WeakMap<object, object>();\n")?;
+      writeln!(
+        writer,
+        "function __borrow<T extends ArrayBufferView>(owner: object, view: T): T {{"
+      )?;
+      writeln!(writer, "  __owners.set(view.buffer, owner);")?;
+      writeln!(writer, "  return view;")?;
+      writeln!(writer, "}}\n")?;
+    }
+
+    Ok(())
+  }
+
   fn lazy_dlopen<W: Write>(&self, writer: &mut W) -> Result<()> {
     writeln!(writer, "let symbols: any;\n")?;
     writeln!(
@@ -183,8 +980,8 @@ impl<'a> Codegen<'a> {
       callback: impl Fn(&mut W, &[T]) -> Result<()>,
     ) -> Result<()> {
       write!(writer, "[")?;
-      if items.len() > 0 {
-        write!(writer, "\n")?;
+      if !items.is_empty() {
+        writeln!(writer)?;
         callback(writer, items)?;
         writeln!(writer, "    ],")?;
       } else {
@@ -195,28 +992,49 @@ impl<'a> Codegen<'a> {
     }
 
     for symbol in self.symbols {
-      match symbol {
-        Inventory::Symbol(symbol) => {
-          writeln!(writer, "  {}: {{", symbol.name)?;
-          write!(writer, "    parameters: ")?;
-          format_bracket(writer, symbol.parameters, |writer, parameters| {
-            for parameter in parameters {
-              writeln!(writer, "      {},", DenoFfiType::from(*parameter))?;
-            }
-            Ok(())
-          })?;
-          writeln!(
-            writer,
-            "    result: {},",
-            DenoFfiType::from(symbol.return_type)
-          )?;
-          writeln!(writer, "    nonblocking: {}", symbol.non_blocking)?;
-          writeln!(writer, "  }},")?;
-        }
-        _ => {}
+      if let Inventory::Symbol(symbol) = symbol {
+        writeln!(writer, "  {}: {{", symbol.name)?;
+        write!(writer, "    parameters: ")?;
+        let mut parameters = symbol
+          .parameters
+          .iter()
+          .map(|param| {
+            self
+              .struct_ffi_type(param.ty, false)
+              .unwrap_or_else(|| DenoFfiType::from(self.ffi_type(param.ty)))
+          })
+          .collect::<Vec<_>>();
+        // The error slot.
+        parameters.push(DenoFfiType("'buffer'".to_string()));
+        format_bracket(writer, &parameters, |writer, parameters| {
+          for parameter in parameters {
+            writeln!(writer, "      {},", parameter)?;
+          }
+          Ok(())
+        })?;
+        writeln!(
+          writer,
+          "    result: {},",
+          self
+            .struct_ffi_type(symbol.return_type, true)
+            .unwrap_or_else(|| {
+              DenoFfiType::result(self.ffi_type(symbol.return_type))
+            })
+        )?;
+        writeln!(writer, "    nonblocking: {}", symbol.non_blocking)?;
+        writeln!(writer, "  }},")?;
       }
     }
 
+    if self.has_symbols() {
+      // Releases buffers handed over by `deno_bindgen::OwnedBuffer`.
+      writeln!(writer, "  __deno_bindgen_free: {{")?;
+      writeln!(writer, "    parameters: ['pointer'],")?;
+      writeln!(writer, "    result: 'void',")?;
+      writeln!(writer, "    nonblocking: false")?;
+      writeln!(writer, "  }},")?;
+    }
+
     Ok(())
   }
 
@@ -231,8 +1049,8 @@ impl<'a> Codegen<'a> {
     ) -> Result<()> {
       let (start, end) = delim;
       write!(writer, "{start}")?;
-      if items.len() > 0 || allow_empty {
-        write!(writer, "\n")?;
+      if !items.is_empty() || allow_empty {
+        writeln!(writer)?;
         callback(writer, items)?;
         write!(writer, "{:indent$}{end}", "", indent = nesting_spaces)?;
       } else {
@@ -245,75 +1063,272 @@ impl<'a> Codegen<'a> {
     for symbol in self.symbols {
       match symbol {
         Inventory::Symbol(symbol) => {
+          let ret_ty = self.ts(symbol.return_type).int64(symbol.int64);
+          let maybe_ret_transform = ret_ty.ret_from_raw("ret");
+          let cleanups = symbol
+            .parameters
+            .iter()
+            .filter_map(|param| {
+              self.ts(param.ty).cleanup(&parameter(param.name))
+            })
+            .collect::<Vec<_>>();
           if !symbol.internal {
+            jsdoc(writer, "", symbol.docs, symbol.deprecated)?;
             write!(writer, "export ")?;
           }
-          write!(writer, "function {}", symbol.name)?;
+          if symbol.non_blocking {
+            write!(writer, "async ")?;
+          }
+          // Internal symbols are called by their native name.
+          let name = if symbol.internal {
+            Cow::Borrowed(symbol.name)
+          } else {
+            self.export_name(symbol.name, symbol.rename)
+          };
+          write!(writer, "function {name}")?;
           format_paren(
             writer,
             symbol.parameters,
             false,
             |writer, parameters| {
-              for (i, parameter) in parameters.iter().enumerate() {
-                writeln!(
-                  writer,
-                  "  arg{}: {},",
-                  i,
-                  TypeScriptType::from(*parameter)
-                )?;
+              for param in parameters {
+                let ident = parameter(param.name);
+                let ty = self.ts(param.ty).int64(symbol.int64);
+                writeln!(writer, "  {ident}: {ty},")?;
               }
               Ok(())
             },
             0,
             ('(', ")"),
           )?;
-          let ret_ty = TypeScriptType::from(symbol.return_type);
           writeln!(
             writer,
             ": {} {{",
             ret_ty.apply_promise(symbol.non_blocking)
           )?;
-          let maybe_ret_transform = ret_ty.from_raw("ret");
-          if maybe_ret_transform.is_some() {
-            write!(writer, "  const ret = ")?;
-          } else {
-            write!(writer, "  return ")?;
+          // Temporary callbacks are created once every other argument is
+          // lowered, and closed however the call ends.
+          let guarded = !cleanups.is_empty();
+          let indent = if guarded { "    " } else { "  " };
+          let mut preludes = Vec::new();
+          let mut callbacks = Vec::new();
+          let mut args = Vec::new();
+          for param in symbol.parameters {
+            let ident = parameter(param.name);
+            let ts = self.ts(param.ty);
+            let is_callback = matches!(param.ty, Type::Function(_));
+            if let Some(prelude) = ts.prelude(&ident, symbol.non_blocking) {
+              if guarded && is_callback {
+                callbacks.push(prelude);
+              } else {
+                preludes.push(prelude);
+              }
+            }
+
+            let raw = ts.into_raw(&ident);
+            if !guarded || is_callback || raw == ident {
+              args.push(raw.into_owned());
+              continue;
+            }
+            let hoisted = raw
+              .split(",\n    ")
+              .enumerate()
+              .map(|(i, expr)| {
+                if expr == ident {
+                  return ident.to_string();
+                }
+                let name = format!("{ident}Arg{i}");
+                preludes.push(format!("const {name} = {expr};"));
+                name
+              })
+              .collect::<Vec<_>>();
+            args.push(hoisted.join(",\n      "));
+          }
+          for prelude in preludes.iter().chain(&callbacks) {
+            writeln!(writer, "  {prelude}")?;
+          }
+          if symbol.non_blocking {
+            // The shared slot could be clobbered while the call is pending.
+            writeln!(writer, "  const __error = new BigUint64Array(1);")?;
+          }
+          if guarded {
+            writeln!(writer, "  try {{")?;
+          }
+          write!(writer, "{indent}const ret = ")?;
+          if symbol.non_blocking {
+            write!(writer, "await ")?;
           }
           write!(writer, "symbols.{}", symbol.name)?;
           format_paren(
             writer,
-            symbol.parameters,
-            false,
-            |writer, parameters| {
-              for (i, parameter) in parameters.iter().enumerate() {
-                let ident = format!("arg{}", i);
-                writeln!(
-                  writer,
-                  "    {},",
-                  TypeScriptType::from(*parameter).into_raw(&ident)
-                )?;
+            &args,
+            true,
+            |writer, args| {
+              for arg in args {
+                writeln!(writer, "{indent}  {arg},")?;
               }
+              writeln!(writer, "{indent}  __error,")?;
               Ok(())
             },
-            2,
+            indent.len(),
             ('(', ")"),
           )?;
 
-          if let Some(ret_transform) = maybe_ret_transform {
-            write!(writer, "\n  return {ret_transform};")?;
+          write!(writer, "\n{indent}__checkError(__error);")?;
+          match maybe_ret_transform {
+            Some(ret_transform) => {
+              write!(writer, "\n{indent}return {ret_transform};")?
+            }
+            None => write!(writer, "\n{indent}return ret;")?,
+          }
+          if guarded {
+            write!(writer, "\n  }} finally {{")?;
+            for cleanup in &cleanups {
+              write!(writer, "\n    {cleanup}")?;
+            }
+            write!(writer, "\n  }}")?;
           }
           writeln!(writer, "\n}}\n")?;
         }
-        Inventory::Struct(Struct { name, methods }) => {
-          write!(writer, "export class {name} ")?;
+        Inventory::Interface(Interface {
+          name,
+          fields,
+          docs,
+          deprecated,
+          ..
+        }) => {
+          let name = class_name(self.symbols, name);
+          jsdoc(writer, "", docs, *deprecated)?;
+          writeln!(writer, "export interface {name} {{")?;
+          for field in *fields {
+            writeln!(writer, "  {}: {};", property(field.name), field.ty)?;
+          }
+          writeln!(writer, "}}\n")?;
+        }
+        Inventory::CStruct(CStruct {
+          name,
+          fields,
+          size,
+          docs,
+          deprecated,
+          ..
+        }) => {
+          let name = class_name(self.symbols, name);
+          jsdoc(writer, "", docs, *deprecated)?;
+          // 64-bit fields are always `bigint`s, as they are read from the
+          // packed bytes.
+          let ts = |ty| self.ts(ty).int64(Int64Mode::BigInt);
+          writeln!(writer, "export interface {name} {{")?;
+          for field in *fields {
+            writeln!(writer, "  {}: {};", property(field.name), ts(field.ty))?;
+          }
+          writeln!(writer, "}}\n")?;
+
+          writeln!(
+            writer,
+            "export function pack{name}(value: {name}): Uint8Array {{"
+          )?;
+          writeln!(writer, "  const buf = new Uint8Array({size});")?;
+          writeln!(writer, "  const view = new DataView(buf.buffer);")?;
+          for field in *fields {
+            let (getter, field_size) = data_view_getter(field.ty);
+            let mut value = format!("value.{}", field.name);
+            if field.ty == Type::Bool {
+              value = format!("{value} ? 1 : 0");
+            } else if is_int64(field.ty) && field_size == 4 {
+              value = format!("Number({value})");
+            }
+            writeln!(
+              writer,
+              "  view.{}({}, {value}{});",
+              getter.replacen("get", "set", 1),
+              field.offset,
+              little_endian(field_size)
+            )?;
+          }
+          writeln!(writer, "  return buf;")?;
+          writeln!(writer, "}}\n")?;
+
+          writeln!(
+            writer,
+            "export function unpack{name}(buf: Uint8Array): {name} {{"
+          )?;
+          writeln!(
+            writer,
+            "  const view = new DataView(buf.buffer, buf.byteOffset);"
+          )?;
+          writeln!(writer, "  return {{")?;
+          for field in *fields {
+            let (getter, field_size) = data_view_getter(field.ty);
+            let mut value = format!(
+              "view.{getter}({}{})",
+              field.offset,
+              little_endian(field_size)
+            );
+            if field.ty == Type::Bool {
+              value = format!("{value} !== 0");
+            } else if is_int64(field.ty) && field_size == 4 {
+              value = format!("BigInt({value})");
+            }
+            writeln!(writer, "    {}: {value},", property(field.name))?;
+          }
+          writeln!(writer, "  }};")?;
+          writeln!(writer, "}}\n")?;
+        }
+        Inventory::Union(Union { name, variants, .. }) => {
+          let name = class_name(self.symbols, name);
+          write!(writer, "export type {name} =")?;
+          for variant in *variants {
+            write!(writer, "\n  | {{ type: {:?}", variant.name)?;
+            for field in variant.fields {
+              write!(writer, "; {}: {}", property(field.name), field.ty)?;
+            }
+            write!(writer, " }}")?;
+          }
+          writeln!(writer, ";\n")?;
+        }
+        Inventory::Enum(Enum { name, variants, .. }) => {
+          let name = class_name(self.symbols, name);
+          writeln!(writer, "export enum {name} {{")?;
+          for variant in *variants {
+            writeln!(writer, "  {} = {},", variant.name, variant.value)?;
+          }
+          writeln!(writer, "}}\n")?;
+        }
+        // Added to the class of the same name.
+        Inventory::Properties(_) => {}
+        Inventory::Struct(Struct {
+          name,
+          methods,
+          docs,
+          deprecated,
+          finalize,
+          ..
+        }) => {
+          let class = class_name(self.symbols, name);
+          jsdoc(writer, "", docs, *deprecated)?;
+          write!(writer, "export class {class} ")?;
 
           format_paren(
             writer,
-            &methods,
+            methods,
             false,
             |writer, methods| {
               writeln!(writer, "  ptr: Deno.PointerObject | null = null;\n")?;
 
+              // Frees instances that are collected without being disposed.
+              if *finalize {
+                writeln!(
+                  writer,
+                  "  static __registry = new FinalizationRegistry("
+                )?;
+                writeln!(
+                  writer,
+                  "    (ptr: Deno.PointerObject | null) => __{name}_dealloc(ptr),"
+                )?;
+                writeln!(writer, "  );\n")?;
+              }
+
               // Internal constructor.
               writeln!(
                 writer,
@@ -321,72 +1336,108 @@ impl<'a> Codegen<'a> {
               )?;
               writeln!(
                 writer,
-                "    const self = Object.create({name}.prototype);"
+                "    const self = Object.create({class}.prototype);"
               )?;
               writeln!(writer, "    self.ptr = ptr;")?;
+              if *finalize {
+                writeln!(
+                  writer,
+                  "    {class}.__registry.register(self, ptr, self);"
+                )?;
+              }
               writeln!(writer, "    return self;")?;
               writeln!(writer, "  }}\n")?;
 
-              // Dispose method (explicit resource management)
+              // Dispose method (explicit resource management), disposing a
+              // consumed object is a no-op.
               writeln!(writer, "  [Symbol.dispose]() {{")?;
-              writeln!(writer, "    this.dealloc();")?;
-              writeln!(writer, "    this.ptr = null;")?;
+              writeln!(writer, "    if (this.ptr !== null) {{")?;
+              writeln!(writer, "      this.dealloc();")?;
+              writeln!(writer, "    }}")?;
               writeln!(writer, "  }}")?;
 
               for method in methods {
                 let mut parameters = method.parameters;
 
-                if !method.is_constructor {
+                // Constructors and static methods take no receiver.
+                let has_receiver = !method.is_constructor && !method.is_static;
+                if has_receiver {
                   // Skip the self ptr argument.
                   parameters = &method.parameters[1..];
                 }
 
                 let method_name = if method.is_constructor {
-                  "constructor"
+                  Cow::Borrowed("constructor")
                 } else {
-                  &method.name
+                  self.export_name(method.name, method.rename)
                 };
 
+                writeln!(writer)?;
+                jsdoc(writer, "  ", method.docs, method.deprecated)?;
                 write!(
                   writer,
-                  "\n  {name}({parameters})",
+                  "  {modifier}{name}({parameters})",
+                  modifier = if method.is_static { "static " } else { "" },
                   name = method_name,
                   parameters = parameters
                     .iter()
-                    .enumerate()
-                    .map(|(i, parameter)| {
-                      format!("arg{}: {}", i, TypeScriptType::from(*parameter))
+                    .map(|param| {
+                      format!(
+                        "{}: {}",
+                        parameter(param.name),
+                        self.ts(param.ty).int64(method.int64)
+                      )
                     })
                     .collect::<Vec<_>>()
                     .join(", "),
                 )?;
 
                 if !method.is_constructor {
-                  let return_type = TypeScriptType::from(method.return_type);
+                  let return_type =
+                    self.ts(method.return_type).int64(method.int64);
                   writeln!(writer, ": {return_type} {{")?;
                 } else {
                   // Typescript doesn't allow constructors to have a return type.
                   writeln!(writer, " {{")?;
                 }
 
+                // The handle is released before the call, Rust owns the
+                // object from here on.
+                if method.consumes_self {
+                  writeln!(writer, "    const self_ = __handle(this);")?;
+                  writeln!(writer, "    this.ptr = null;")?;
+                  if *finalize {
+                    writeln!(
+                      writer,
+                      "    {class}.__registry.unregister(this);"
+                    )?;
+                  }
+                }
+
+                // Slices borrowed from the object are tied to it.
+                let borrows = matches!(method.return_type, Type::Buffer(_));
+                write!(writer, "    return ")?;
+                if borrows {
+                  write!(writer, "__borrow(this, ")?;
+                }
+
                 // Apply name mangling.
-                write!(writer, "    return __{}_{}", name, method.name)?;
+                write!(writer, "__{}_{}", name, method.name)?;
                 format_paren(
                   writer,
                   parameters,
-                  !method.is_constructor,
+                  has_receiver,
                   |writer, parameters| {
-                    if !method.is_constructor {
-                      writeln!(writer, "      this.ptr,",)?;
+                    if method.consumes_self {
+                      writeln!(writer, "      self_,")?;
+                    } else if has_receiver {
+                      writeln!(writer, "      __handle(this),")?;
                     }
 
-                    for (i, parameter) in parameters.iter().enumerate() {
-                      let ident = format!("arg{}", i);
-                      writeln!(
-                        writer,
-                        "      {},",
-                        TypeScriptType::from(*parameter).into_raw(&ident)
-                      )?;
+                    // Arguments are lowered and the return value lifted by
+                    // the internal wrapper.
+                    for param in parameters {
+                      writeln!(writer, "      {},", parameter(param.name))?;
                     }
 
                     Ok(())
@@ -394,9 +1445,31 @@ impl<'a> Codegen<'a> {
                   4,
                   ('(', ")"),
                 )?;
+                if borrows {
+                  write!(writer, ")")?;
+                }
 
                 writeln!(writer, "\n  }}")?;
               }
+
+              for property in self.properties(name) {
+                let ty = self.ts(property.ty);
+                let accessor = self.case.apply(property.name);
+                writeln!(writer, "\n  get {accessor}(): {ty} {{")?;
+                writeln!(
+                  writer,
+                  "    return __{name}__get_{}(__handle(this));",
+                  property.name
+                )?;
+                writeln!(writer, "  }}\n")?;
+                writeln!(writer, "  set {accessor}(value: {ty}) {{")?;
+                writeln!(
+                  writer,
+                  "    __{name}__set_{}(__handle(this), value);",
+                  property.name
+                )?;
+                writeln!(writer, "  }}")?;
+              }
               Ok(())
             },
             0,
@@ -424,8 +1497,122 @@ impl<'a> Generator for Codegen<'a> {
 
     prelude(&mut writer)?;
     self.dlopen(&mut writer)?;
+    self.runtime(&mut writer)?;
     self.exports(&mut writer)?;
 
     Ok(())
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use std::path::Path;
+
+  use super::Codegen;
+  use crate::codegen::Case;
+  use crate::codegen::Generator;
+  use crate::inventory::CField;
+  use crate::inventory::CStruct;
+  use crate::inventory::Field;
+  use crate::inventory::Interface;
+  use crate::inventory::Inventory;
+  use crate::inventory::Struct;
+  use crate::Int64Mode;
+  use crate::Parameter;
+  use crate::Symbol;
+  use crate::Type;
+
+  #[test]
+  fn test_jsdoc() {
+    let symbols = [
+      Inventory::Symbol(Symbol {
+        name: "greet",
+        parameters: &[Parameter {
+          name: "name",
+          ty: Type::Str,
+        }],
+        return_type: Type::Uint32,
+        non_blocking: false,
+        internal: false,
+        is_constructor: false,
+        is_static: false,
+        consumes_self: false,
+        int64: Int64Mode::Auto,
+        docs: "Says hello.\n\nUses the default greeting.",
+        deprecated: Some("use `greet_with`"),
+        rename: None,
+      }),
+      Inventory::Struct(Struct {
+        name: "Greeter",
+        methods: &[],
+        docs: "A greeting.",
+        deprecated: Some(""),
+        rename: None,
+        finalize: false,
+      }),
+      Inventory::Interface(Interface {
+        name: "Recipient",
+        fields: &[Field {
+          name: "name",
+          ty: "string",
+        }],
+        docs: "Where a greeting is sent.",
+        deprecated: None,
+        rename: None,
+      }),
+      Inventory::CStruct(CStruct {
+        name: "Point",
+        fields: &[CField {
+          name: "x",
+          ty: Type::Float64,
+          offset: 0,
+        }],
+        size: 8,
+        align: 8,
+        docs: "",
+        deprecated: Some("*/ is not a point"),
+        rename: None,
+      }),
+    ];
+
+    let mut out = Vec::new();
+    Codegen::new(&symbols, Path::new("lib.so"), false, Case::Snake)
+      .generate(&mut out)
+      .unwrap();
+    let out = String::from_utf8(out).unwrap();
+
+    let cases = [
+      "/**\n * Says hello.\n *\n * Uses the default greeting.\n *\n * @deprecated use `greet_with`\n */\nexport function greet(",
+      "/**\n * A greeting.\n *\n * @deprecated\n */\nexport class Greeter ",
+      "/**\n * Where a greeting is sent.\n */\nexport interface Recipient {",
+      "/**\n * @deprecated *\\/ is not a point\n */\nexport interface Point {",
+    ];
+    for expected in cases {
+      assert!(out.contains(expected), "missing {expected:?} in:\n{out}");
+    }
+    assert!(!out.contains("@param") && !out.contains("@returns"));
+  }
+
+  #[test]
+  fn test_align_of() {
+    // Laid out like `deno_bindgen::Optional`.
+    #[repr(C)]
+    struct Optional<T> {
+      _is_some: u8,
+      value: T,
+    }
+
+    let cases = [
+      (Type::Bool, std::mem::offset_of!(Optional<bool>, value)),
+      (Type::Int16, std::mem::offset_of!(Optional<i16>, value)),
+      (Type::Uint32, std::mem::offset_of!(Optional<u32>, value)),
+      (Type::Uint64, std::mem::offset_of!(Optional<u64>, value)),
+      (Type::Isize, std::mem::offset_of!(Optional<isize>, value)),
+      (Type::Float32, std::mem::offset_of!(Optional<f32>, value)),
+      (Type::Float64, std::mem::offset_of!(Optional<f64>, value)),
+    ];
+    for (ty, offset) in cases {
+      assert_eq!(super::align_of(ty), offset, "{ty:?}");
+    }
+  }
+}
@@ -4,17 +4,64 @@ use crate::inventory::Inventory;
 
 mod deno;
 
+/// The crate version, NUL terminated. [`Options`] is passed by value from the
+/// CLI to the library, so both must be built against the same version.
+pub const VERSION: &str = concat!(env!("CARGO_PKG_VERSION"), "\0");
+
 pub struct Options {
   pub target: Target,
   pub out: Option<PathBuf>,
   pub local_dylib_path: PathBuf,
   pub lazy_init: bool,
+  pub case: Case,
 }
 
 pub enum Target {
   Deno,
 }
 
+/// The naming convention of exported functions, methods and properties.
+/// Names set by `#[deno_bindgen(rename)]` are kept as is.
+#[derive(Debug, Default, Clone, Copy)]
+pub enum Case {
+  #[default]
+  Snake,
+  Camel,
+}
+
+impl Case {
+  pub fn apply(self, name: &str) -> String {
+    match self {
+      Case::Snake => name.to_string(),
+      Case::Camel => {
+        // Leading underscores usually mark private names, keep them.
+        let rest = name.trim_start_matches('_');
+        let mut camel = name[..name.len() - rest.len()].to_string();
+        for (i, word) in rest.split('_').filter(|w| !w.is_empty()).enumerate() {
+          let mut chars = word.chars();
+          if i > 0 {
+            camel.extend(chars.next().map(|c| c.to_ascii_uppercase()));
+          }
+          camel.extend(chars);
+        }
+        camel
+      }
+    }
+  }
+}
+
+impl std::str::FromStr for Case {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "snake" => Ok(Case::Snake),
+      "camel" => Ok(Case::Camel),
+      _ => Err(format!("unknown case `{s}`, expected `snake` or `camel`")),
+    }
+  }
+}
+
 pub trait Generator {
   fn generate<W: std::io::Write>(&mut self, writer: W) -> std::io::Result<()>;
 }
@@ -24,9 +71,12 @@ pub fn generate(
   opt: Options,
 ) -> std::io::Result<()> {
   let mut codegen = match opt.target {
-    Target::Deno => {
-      deno::Codegen::new(symbols, &opt.local_dylib_path, opt.lazy_init)
-    }
+    Target::Deno => deno::Codegen::new(
+      symbols,
+      &opt.local_dylib_path,
+      opt.lazy_init,
+      opt.case,
+    ),
   };
 
   if let Some(out) = opt.out {
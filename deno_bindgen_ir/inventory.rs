@@ -1,12 +1,115 @@
 use crate::Symbol;
+use crate::Type;
 
 #[derive(Debug)]
 pub struct Struct {
   pub name: &'static str,
   pub methods: &'static [Symbol],
+  /// The `///` comments on the struct.
+  pub docs: &'static str,
+  /// The note of a `#[deprecated]` struct, if any.
+  pub deprecated: Option<&'static str>,
+  /// The class name, set by `#[deno_bindgen(rename)]`.
+  pub rename: Option<&'static str>,
+  /// Whether instances are freed once garbage collected, set by
+  /// `#[deno_bindgen(finalize)]`.
+  pub finalize: bool,
+}
+
+/// A field of an [`Interface`], typed in TypeScript.
+#[derive(Debug)]
+pub struct Field {
+  pub name: &'static str,
+  pub ty: &'static str,
+}
+
+/// A `#[deno_bindgen(serde)]` struct, passed by value as JSON.
+#[derive(Debug)]
+pub struct Interface {
+  pub name: &'static str,
+  pub fields: &'static [Field],
+  /// The `///` comments on the struct.
+  pub docs: &'static str,
+  /// The note of a `#[deprecated]` struct, if any.
+  pub deprecated: Option<&'static str>,
+  /// The interface name, set by `#[deno_bindgen(rename)]`.
+  pub rename: Option<&'static str>,
+}
+
+/// A field of a [`CStruct`] and where it is laid out.
+#[derive(Debug)]
+pub struct CField {
+  pub name: &'static str,
+  pub ty: Type,
+  /// The byte offset of the field.
+  pub offset: usize,
+}
+
+/// A `#[deno_bindgen(repr_c)]` struct, passed by value as a Deno FFI struct
+/// and exported as a TypeScript interface.
+#[derive(Debug)]
+pub struct CStruct {
+  pub name: &'static str,
+  pub fields: &'static [CField],
+  /// The size of the struct in bytes, including padding.
+  pub size: usize,
+  /// The alignment of the struct in bytes.
+  pub align: usize,
+  /// The `///` comments on the struct.
+  pub docs: &'static str,
+  /// The note of a `#[deprecated]` struct, if any.
+  pub deprecated: Option<&'static str>,
+  /// The interface name, set by `#[deno_bindgen(rename)]`.
+  pub rename: Option<&'static str>,
+}
+
+/// A variant of an [`Enum`] and its discriminant.
+#[derive(Debug)]
+pub struct Variant {
+  pub name: &'static str,
+  pub value: i64,
+}
+
+/// A C-like enum, passed as its `#[repr]` integer.
+#[derive(Debug)]
+pub struct Enum {
+  pub name: &'static str,
+  pub repr: Type,
+  pub variants: &'static [Variant],
+  /// The enum name, set by `#[deno_bindgen(rename)]`.
+  pub rename: Option<&'static str>,
+}
+
+/// An enum with data-carrying variants, passed as JSON. Each variant is an
+/// interface tagged with its name in a `type` field.
+#[derive(Debug)]
+pub struct Union {
+  pub name: &'static str,
+  pub variants: &'static [Interface],
+  /// The type name, set by `#[deno_bindgen(rename)]`.
+  pub rename: Option<&'static str>,
+}
+
+/// A `pub` field of a class, read and written through accessor symbols.
+#[derive(Debug)]
+pub struct Property {
+  pub name: &'static str,
+  pub ty: Type,
+}
+
+/// The `pub` fields of a `#[deno_bindgen]` struct, added to its class.
+#[derive(Debug)]
+pub struct Properties {
+  pub name: &'static str,
+  pub properties: &'static [Property],
 }
 
 pub enum Inventory {
   Symbol(Symbol),
   Struct(Struct),
+  Properties(Properties),
+  Interface(Interface),
+  CStruct(CStruct),
+  Enum(Enum),
+  Union(Union),
 }
@@ -1,4 +1,5 @@
 use proc_macro2::Ident;
+use proc_macro2::Span;
 use quote::quote;
 use quote::ToTokens;
 use syn::parse_quote;
@@ -13,6 +14,7 @@ pub use inventory::Inventory;
 pub enum Type {
   #[default]
   Void,
+  Bool,
   Uint8,
   Uint16,
   Uint32,
@@ -21,12 +23,65 @@ pub enum Type {
   Int16,
   Int32,
   Int64,
+  /// Pointer-sized integers, passed as Deno `usize` and `isize`.
+  Usize,
+  Isize,
   Float32,
   Float64,
   Pointer,
-  Buffer,
+  /// Slices of numbers, mapped to the matching typed array. Only methods
+  /// return them, as a view of memory owned by the object.
+  Buffer(&'static Type),
+  /// Owned `Vec<T>` and `Box<[T]>` of numbers, returned as a copy in the
+  /// matching typed array.
+  Vec(&'static Type),
+  Str,
+  String,
 
   CustomType(&'static str),
+  /// `Result<T, E>` return values, where `E: Display`.
+  Result(&'static Type),
+  /// `Option<T>`, mapped to `T | null`.
+  Optional(&'static Type),
+  /// JavaScript functions, passed as `Deno.UnsafeCallback`s.
+  Function(&'static Callback),
+}
+
+/// The signature of a [`Type::Function`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Callback {
+  pub parameters: &'static [Type],
+  pub result: Type,
+  /// Whether the function takes an `impl Fn` rather than an `extern "C" fn`.
+  pub closure: bool,
+  /// Whether Rust may keep the callback after the call returns, set by
+  /// `#[deno_bindgen(stores_callbacks)]`.
+  pub stored: bool,
+}
+
+/// How 64-bit integers are represented in JavaScript, chosen with
+/// `#[deno_bindgen(int64 = "...")]`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum Int64Mode {
+  /// `number | bigint`, as handed out by Deno: a `bigint` only when the value
+  /// is outside the safe integer range.
+  #[default]
+  Auto,
+  /// Always a `bigint`.
+  BigInt,
+  /// Always a `number`, unsafe integers throw a `RangeError`.
+  Number,
+}
+
+impl ToTokens for Int64Mode {
+  fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+    let mode = match self {
+      Self::Auto => quote! { deno_bindgen::Int64Mode::Auto },
+      Self::BigInt => quote! { deno_bindgen::Int64Mode::BigInt },
+      Self::Number => quote! { deno_bindgen::Int64Mode::Number },
+    };
+    tokens.extend(mode);
+  }
 }
 
 pub type RawTypes = &'static [Type];
@@ -34,8 +89,18 @@ pub type RawTypes = &'static [Type];
 impl Type {
   pub fn raw(&self) -> RawTypes {
     match self {
-      Self::Buffer => &[Self::Pointer, Self::Uint32],
-      Self::Pointer | Self::CustomType(..) => &[Self::Pointer],
+      Self::Buffer(_) | Self::Str | Self::String => {
+        &[Self::Pointer, Self::Usize]
+      }
+      Self::Pointer => &[Self::Pointer],
+      Self::Function(_) => Box::leak(Box::new([*self])),
+      // Picked by the type's `deno_bindgen::IntoHandle` impl.
+      Self::CustomType(..) => Box::leak(Box::new([*self])),
+      // Prefixed with a flag telling whether the value is present.
+      Self::Optional(Self::Buffer(_) | Self::Str | Self::String) => {
+        &[Self::Uint8, Self::Pointer, Self::Usize]
+      }
+      Self::Optional(ty) => Box::leak(Box::new([Self::Uint8, **ty])),
       _ => &[],
     }
   }
@@ -43,30 +108,77 @@ impl Type {
   pub fn is_number(&self) -> bool {
     !matches!(
       self,
-      Self::Void | Self::Pointer | Self::Buffer | Self::CustomType(_)
+      Self::Void
+        | Self::Bool
+        | Self::Pointer
+        | Self::Buffer(_)
+        | Self::Vec(_)
+        | Self::Str
+        | Self::String
+        | Self::CustomType(_)
+        | Self::Result(_)
+        | Self::Optional(_)
+        | Self::Function(_)
     )
   }
 
+  /// Numbers and `bool`s, which Deno passes as is.
+  pub fn is_scalar(&self) -> bool {
+    *self == Self::Bool || self.is_number()
+  }
+
+  /// Whether returning the type can fail with an error message.
+  pub fn is_fallible(&self) -> bool {
+    matches!(self, Self::Result(_))
+  }
+
+  /// The trailing out parameter of every symbol. On error or panic, it is
+  /// set to a `deno_bindgen::OwnedBuffer` holding the error message.
+  pub fn error_slot() -> Ident {
+    Ident::new("__error", Span::mixed_site())
+  }
+
   pub fn apply_arg_transform(
     &self,
     name: &mut Box<Pat>,
     args: &[Ident],
   ) -> Option<proc_macro2::TokenStream> {
     match self {
-      Self::Buffer => {
+      Self::Buffer(_) => {
         let pointer = &args[0];
         let length = &args[1];
         Some(quote! {
           let #name = unsafe {
-            std::slice::from_raw_parts_mut(#pointer as _, #length as usize)
+            deno_bindgen::FromBuffer::from_buffer(#pointer, #length)
           };
         })
       }
-      Self::CustomType(_) => {
+      Self::Str | Self::String => {
         let pointer = &args[0];
+        let length = &args[1];
+        // The generated bindings always encode with `TextEncoder`, which
+        // produces valid UTF-8.
+        let str = quote! {
+          if #length == 0 {
+            ""
+          } else {
+            unsafe {
+              std::str::from_utf8_unchecked(
+                std::slice::from_raw_parts(#pointer as _, #length)
+              )
+            }
+          }
+        };
+        Some(if *self == Self::String {
+          quote! { let #name = String::from(#str); }
+        } else {
+          quote! { let #name = #str; }
+        })
+      }
+      Self::CustomType(_) => {
+        let raw = &args[0];
         Some(quote! {
-          debug_assert!(!#pointer.is_null());
-          let #name = unsafe { &mut *(#pointer as *mut _) };
+          let #name = unsafe { deno_bindgen::FromHandle::from_handle(#raw) };
         })
       }
       Self::Pointer => {
@@ -75,6 +187,54 @@ impl Type {
           let #name = #pointer as _;
         })
       }
+      Self::Function(callback) => {
+        let pointer = &args[0];
+        if !callback.closure {
+          return Some(quote! { let #name = #pointer; });
+        }
+
+        let params = callback.parameters;
+        let idents = (0..params.len())
+          .map(|i| Ident::new(&format!("__{i}"), Span::mixed_site()))
+          .collect::<Vec<_>>();
+        Some(quote! {
+          let #name = move |#(#idents: #params),*| #pointer(#(#idents),*);
+        })
+      }
+      Self::Optional(Self::Buffer(_)) => {
+        let flag = &args[0];
+        let pointer = &args[1];
+        let length = &args[2];
+        Some(quote! {
+          let #name = if #flag == 0 {
+            None
+          } else {
+            Some(unsafe {
+              deno_bindgen::FromBuffer::from_buffer(#pointer, #length)
+            })
+          };
+        })
+      }
+      Self::Optional(ty) => {
+        let flag = &args[0];
+        let value = match ty.apply_arg_transform(name, &args[1..]) {
+          Some(transform) => quote! {
+            #transform
+            #name
+          },
+          None => {
+            let value = &args[1];
+            quote! { #value }
+          }
+        };
+        Some(quote! {
+          let #name = if #flag == 0 {
+            None
+          } else {
+            Some({ #value })
+          };
+        })
+      }
       _ => None,
     }
   }
@@ -89,8 +249,56 @@ impl Type {
         let #name = #arg as _;
       }),
       Self::CustomType(_) => Some(quote! {
-        let #name = Box::into_raw(Box::new(#arg)) as *mut _;
+        let #name = deno_bindgen::IntoHandle::into_handle(#arg);
+      }),
+      Self::Optional(Self::CustomType(_)) => Some(quote! {
+        let #name = deno_bindgen::Optional::from(
+          #arg.map(deno_bindgen::IntoHandle::into_handle)
+        );
+      }),
+      Self::String => Some(quote! {
+        let #name = deno_bindgen::OwnedBuffer::into_raw(#arg.into_bytes()) as *mut _;
       }),
+      Self::Vec(_) => Some(quote! {
+        let #name = deno_bindgen::OwnedBuffer::into_raw(Vec::from(#arg)) as *mut _;
+      }),
+      Self::Buffer(_) => Some(quote! {
+        let #name = deno_bindgen::BorrowedSlice::from(#arg);
+      }),
+      Self::Result(ty) => {
+        let transform = ty.apply_ret_transform(name, arg.clone());
+        let error = Self::error_slot();
+        Some(quote! {
+          let #name: #ty = match #arg {
+            Ok(#arg) => {
+              #transform
+              #name
+            }
+            Err(err) => {
+              let message = err.to_string().into_bytes();
+              unsafe {
+                *#error = deno_bindgen::OwnedBuffer::into_raw(message);
+                std::mem::zeroed()
+              }
+            }
+          };
+        })
+      }
+      Self::Optional(ty) if ty.is_scalar() => Some(quote! {
+        let #name = deno_bindgen::Optional::from(#arg);
+      }),
+      Self::Optional(ty) => {
+        let transform = ty.apply_ret_transform(name, arg.clone());
+        Some(quote! {
+          let #name: *const () = match #arg {
+            Some(#arg) => {
+              #transform
+              #name
+            }
+            None => std::ptr::null(),
+          };
+        })
+      }
       _ => None,
     }
   }
@@ -98,6 +306,7 @@ impl Type {
   pub fn to_ident(&self) -> syn::Expr {
     match self {
       Self::Void => parse_quote!(deno_bindgen::Type::Void),
+      Self::Bool => parse_quote!(deno_bindgen::Type::Bool),
       Self::Uint8 => parse_quote!(deno_bindgen::Type::Uint8),
       Self::Uint16 => parse_quote!(deno_bindgen::Type::Uint16),
       Self::Uint32 => parse_quote!(deno_bindgen::Type::Uint32),
@@ -106,11 +315,42 @@ impl Type {
       Self::Int16 => parse_quote!(deno_bindgen::Type::Int16),
       Self::Int32 => parse_quote!(deno_bindgen::Type::Int32),
       Self::Int64 => parse_quote!(deno_bindgen::Type::Int64),
+      Self::Usize => parse_quote!(deno_bindgen::Type::Usize),
+      Self::Isize => parse_quote!(deno_bindgen::Type::Isize),
       Self::Float32 => parse_quote!(deno_bindgen::Type::Float32),
       Self::Float64 => parse_quote!(deno_bindgen::Type::Float64),
       Self::Pointer => parse_quote!(deno_bindgen::Type::Pointer),
-      Self::Buffer => parse_quote!(deno_bindgen::Type::Buffer),
+      Self::Buffer(ty) => {
+        let ty = ty.to_ident();
+        parse_quote!(deno_bindgen::Type::Buffer(&#ty))
+      }
+      Self::Vec(ty) => {
+        let ty = ty.to_ident();
+        parse_quote!(deno_bindgen::Type::Vec(&#ty))
+      }
+      Self::Str => parse_quote!(deno_bindgen::Type::Str),
+      Self::String => parse_quote!(deno_bindgen::Type::String),
       Self::CustomType(s) => parse_quote!(deno_bindgen::Type::CustomType(#s)),
+      Self::Result(ty) => {
+        let ty = ty.to_ident();
+        parse_quote!(deno_bindgen::Type::Result(&#ty))
+      }
+      Self::Optional(ty) => {
+        let ty = ty.to_ident();
+        parse_quote!(deno_bindgen::Type::Optional(&#ty))
+      }
+      Self::Function(callback) => {
+        let parameters = callback.parameters.iter().map(|ty| ty.to_ident());
+        let result = callback.result.to_ident();
+        let closure = callback.closure;
+        let stored = callback.stored;
+        parse_quote!(deno_bindgen::Type::Function(&deno_bindgen::Callback {
+          parameters: &[#(#parameters),*],
+          result: #result,
+          closure: #closure,
+          stored: #stored,
+        }))
+      }
     }
   }
 }
@@ -119,6 +359,7 @@ impl ToTokens for Type {
   fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
     let ty = match self {
       Self::Void => quote! { () },
+      Self::Bool => quote! { bool },
       Self::Uint8 => quote! { u8 },
       Self::Uint16 => quote! { u16 },
       Self::Uint32 => quote! { u32 },
@@ -127,33 +368,80 @@ impl ToTokens for Type {
       Self::Int16 => quote! { i16 },
       Self::Int32 => quote! { i32 },
       Self::Int64 => quote! { i64 },
+      Self::Usize => quote! { usize },
+      Self::Isize => quote! { isize },
       Self::Float32 => quote! { f32 },
       Self::Float64 => quote! { f64 },
-      Self::CustomType(_) | Self::Pointer => quote! { *const () },
-      Self::Buffer => quote! { *mut u8 },
+      Self::CustomType(name) => {
+        let name = Ident::new(name, Span::call_site());
+        quote! { <#name as deno_bindgen::IntoHandle>::Raw }
+      }
+      Self::Pointer | Self::String | Self::Vec(_) => quote! { *const () },
+      Self::Buffer(_) => quote! { deno_bindgen::BorrowedSlice },
+      Self::Str => quote! { *mut u8 },
+      Self::Result(ty) => quote! { #ty },
+      Self::Optional(ty @ Self::CustomType(_)) => {
+        quote! { deno_bindgen::Optional<#ty> }
+      }
+      Self::Optional(ty) if ty.is_scalar() => {
+        quote! { deno_bindgen::Optional<#ty> }
+      }
+      Self::Optional(_) => quote! { *const () },
+      Self::Function(callback) => {
+        let parameters = callback.parameters;
+        match callback.result {
+          Self::Void => quote! { extern "C" fn(#(#parameters),*) },
+          result => quote! { extern "C" fn(#(#parameters),*) -> #result },
+        }
+      }
     };
 
     tokens.extend(ty);
   }
 }
 
+/// A parameter of a [`Symbol`], named as in the Rust source.
+#[derive(Debug, Copy, Clone)]
+pub struct Parameter {
+  pub name: &'static str,
+  pub ty: Type,
+}
+
 #[derive(Debug)]
 pub struct Symbol {
   pub name: &'static str,
-  pub parameters: &'static [Type],
+  pub parameters: &'static [Parameter],
   pub return_type: Type,
   pub non_blocking: bool,
   pub internal: bool,
   pub is_constructor: bool,
+  /// Associated functions without a receiver, exported as `static` methods.
+  pub is_static: bool,
+  /// Methods taking `self` by value, the handle is invalidated by the call.
+  pub consumes_self: bool,
+  /// How 64-bit integer parameters and return values are typed.
+  pub int64: Int64Mode,
+  /// The `///` comments on the function.
+  pub docs: &'static str,
+  /// The note of a `#[deprecated]` function, if any.
+  pub deprecated: Option<&'static str>,
+  /// The name exported to TypeScript, set by `#[deno_bindgen(rename)]`.
+  pub rename: Option<&'static str>,
 }
 
 pub struct SymbolBuilder {
   name: Ident,
-  parameters: Vec<Type>,
+  parameters: Vec<(String, Type)>,
   return_type: Type,
   non_blocking: bool,
   internal: bool,
   is_constructor: bool,
+  is_static: bool,
+  consumes_self: bool,
+  int64: Int64Mode,
+  docs: String,
+  deprecated: Option<String>,
+  rename: Option<String>,
 }
 
 impl SymbolBuilder {
@@ -165,6 +453,12 @@ impl SymbolBuilder {
       non_blocking: false,
       internal: false,
       is_constructor: false,
+      is_static: false,
+      consumes_self: false,
+      int64: Int64Mode::Auto,
+      docs: String::new(),
+      deprecated: None,
+      rename: None,
     }
   }
 
@@ -172,8 +466,8 @@ impl SymbolBuilder {
     self.name = name;
   }
 
-  pub fn push(&mut self, ty: Type) {
-    self.parameters.push(ty);
+  pub fn push(&mut self, name: String, ty: Type) {
+    self.parameters.push((name, ty));
   }
 
   pub fn return_type(&mut self, ty: Type) {
@@ -191,6 +485,30 @@ impl SymbolBuilder {
   pub fn is_constructor(&mut self, is_constructor: bool) {
     self.is_constructor = is_constructor;
   }
+
+  pub fn is_static(&mut self, is_static: bool) {
+    self.is_static = is_static;
+  }
+
+  pub fn consumes_self(&mut self, consumes_self: bool) {
+    self.consumes_self = consumes_self;
+  }
+
+  pub fn int64(&mut self, int64: Int64Mode) {
+    self.int64 = int64;
+  }
+
+  pub fn docs(&mut self, docs: String) {
+    self.docs = docs;
+  }
+
+  pub fn deprecated(&mut self, deprecated: Option<String>) {
+    self.deprecated = deprecated;
+  }
+
+  pub fn rename(&mut self, rename: Option<String>) {
+    self.rename = rename;
+  }
 }
 
 impl ToTokens for SymbolBuilder {
@@ -198,13 +516,28 @@ impl ToTokens for SymbolBuilder {
     let parameters = &self
       .parameters
       .iter()
-      .map(|ty| ty.to_ident())
+      .map(|(name, ty)| {
+        let ty = ty.to_ident();
+        quote! { deno_bindgen::Parameter { name: #name, ty: #ty } }
+      })
       .collect::<Vec<_>>();
     let return_type = &self.return_type.to_ident();
     let non_blocking = &self.non_blocking;
     let name = &self.name;
     let internal = &self.internal;
     let is_constructor = &self.is_constructor;
+    let is_static = &self.is_static;
+    let consumes_self = &self.consumes_self;
+    let int64 = &self.int64;
+    let docs = &self.docs;
+    let deprecated = match self.deprecated {
+      Some(ref note) => quote! { Some(#note) },
+      None => quote! { None },
+    };
+    let rename = match self.rename {
+      Some(ref rename) => quote! { Some(#rename) },
+      None => quote! { None },
+    };
 
     tokens.extend(quote! {
        deno_bindgen::Symbol {
@@ -214,6 +547,12 @@ impl ToTokens for SymbolBuilder {
           non_blocking: #non_blocking,
           internal: #internal,
           is_constructor: #is_constructor,
+          is_static: #is_static,
+          consumes_self: #consumes_self,
+          int64: #int64,
+          docs: #docs,
+          deprecated: #deprecated,
+          rename: #rename,
        }
     });
   }
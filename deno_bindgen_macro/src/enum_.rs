@@ -0,0 +1,239 @@
+use deno_bindgen_ir::Type;
+use proc_macro2::Ident;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::format_ident;
+use syn::Fields;
+use syn::ItemEnum;
+
+use crate::struct_::ts_type;
+use crate::util::Error;
+use crate::util::Result;
+use crate::EnumAttributes;
+
+fn repr(enum_: &ItemEnum) -> Result<(Ident, Type)> {
+  let mut repr = None;
+  for attr in enum_
+    .attrs
+    .iter()
+    .filter(|attr| attr.path().is_ident("repr"))
+  {
+    attr
+      .parse_nested_meta(|meta| {
+        if let Some(ident) = meta.path.get_ident() {
+          repr = Some(ident.clone());
+        }
+        Ok(())
+      })
+      .map_err(|_| Error::Repr)?;
+  }
+
+  let repr = repr.ok_or(Error::Repr)?;
+  let ty = match repr.to_string().as_str() {
+    "u8" => Type::Uint8,
+    "u16" => Type::Uint16,
+    "u32" => Type::Uint32,
+    "i8" => Type::Int8,
+    "i16" => Type::Int16,
+    "i32" => Type::Int32,
+    _ => return Err(Error::Repr),
+  };
+  Ok((repr, ty))
+}
+
+pub fn handle(enum_: ItemEnum, attrs: EnumAttributes) -> Result<TokenStream2> {
+  if enum_.generics.params.first().is_some() {
+    return Err(Error::Generics);
+  }
+
+  if enum_.generics.where_clause.is_some() {
+    return Err(Error::WhereClause);
+  }
+
+  let rename = match attrs.rename {
+    Some(ref rename) => quote::quote! { Some(#rename) },
+    None => quote::quote! { None },
+  };
+
+  if enum_
+    .variants
+    .iter()
+    .any(|variant| !matches!(variant.fields, Fields::Unit))
+  {
+    return handle_data(enum_, rename);
+  }
+
+  let (repr, ty) = repr(&enum_)?;
+  let repr_ty = ty.to_ident();
+
+  let ty_str = &enum_.ident;
+  let variants = enum_
+    .variants
+    .iter()
+    .map(|variant| &variant.ident)
+    .collect::<Vec<_>>();
+
+  Ok(quote::quote! {
+    #enum_
+
+    impl deno_bindgen::FromHandle for #ty_str {
+        type Raw = #repr;
+
+        unsafe fn from_handle(raw: #repr) -> Self {
+            // Never trust discriminants coming from JavaScript.
+            match raw {
+                #(raw if raw == #ty_str::#variants as #repr => #ty_str::#variants,)*
+                _ => panic!(
+                    "invalid discriminant {} for {}",
+                    raw,
+                    stringify!(#ty_str)
+                ),
+            }
+        }
+    }
+
+    impl deno_bindgen::FieldlessEnum for #ty_str {}
+
+    impl deno_bindgen::IntoHandle for #ty_str {
+        type Raw = #repr;
+
+        fn into_handle(self) -> #repr {
+            self as #repr
+        }
+    }
+
+    const _: () = {
+      #[deno_bindgen::linkme::distributed_slice(deno_bindgen::INVENTORY)]
+      pub static _A: deno_bindgen::Inventory = deno_bindgen::Inventory::Enum(
+        deno_bindgen::inventory::Enum {
+          name: stringify!(#ty_str),
+          repr: #repr_ty,
+          variants: &[#(
+            deno_bindgen::inventory::Variant {
+              name: stringify!(#variants),
+              value: #ty_str::#variants as i64,
+            }
+          ),*],
+          rename: #rename,
+        }
+      );
+    };
+  })
+}
+
+/// Enums with data-carrying variants, passed as `{ type: "Variant", ... }`
+/// objects. Tuple fields are named by their index.
+fn handle_data(enum_: ItemEnum, rename: TokenStream2) -> Result<TokenStream2> {
+  let ty_str = &enum_.ident;
+
+  let mut decode = Vec::new();
+  let mut encode = Vec::new();
+  let mut variants = Vec::new();
+  for variant in &enum_.variants {
+    let ident = &variant.ident;
+    let tag = ident.to_string();
+
+    let names = match variant.fields {
+      Fields::Named(ref fields) => fields
+        .named
+        .iter()
+        .map(|field| field.ident.as_ref().unwrap().to_string())
+        .collect(),
+      Fields::Unnamed(ref fields) => {
+        (0..fields.unnamed.len()).map(|i| i.to_string()).collect()
+      }
+      Fields::Unit => Vec::new(),
+    };
+    let bindings = match variant.fields {
+      Fields::Named(_) => {
+        names.iter().map(|name| format_ident!("{name}")).collect()
+      }
+      _ => (0..names.len())
+        .map(|i| format_ident!("__{i}"))
+        .collect::<Vec<_>>(),
+    };
+
+    let (pattern, value) = match variant.fields {
+      Fields::Named(_) => (
+        quote::quote! { #ty_str::#ident { #(#bindings),* } },
+        quote::quote! {
+          #ty_str::#ident {
+            #(#bindings: deno_bindgen::from_field(&mut fields, #names)),*
+          }
+        },
+      ),
+      Fields::Unnamed(_) => (
+        quote::quote! { #ty_str::#ident(#(#bindings),*) },
+        quote::quote! {
+          #ty_str::#ident(
+            #(deno_bindgen::from_field(&mut fields, #names)),*
+          )
+        },
+      ),
+      Fields::Unit => (
+        quote::quote! { #ty_str::#ident },
+        quote::quote! { #ty_str::#ident },
+      ),
+    };
+    decode.push(quote::quote! { #tag => #value, });
+    encode.push(quote::quote! {
+      #pattern => deno_bindgen::serde_json::json!({
+        "type": #tag,
+        #(#names: #bindings),*
+      }),
+    });
+
+    let tys = variant
+      .fields
+      .iter()
+      .map(|field| ts_type(&field.ty))
+      .collect::<Result<Vec<_>>>()?;
+    variants.push(quote::quote! {
+      deno_bindgen::inventory::Interface {
+        name: #tag,
+        fields: &[#(deno_bindgen::inventory::Field { name: #names, ty: #tys }),*],
+        docs: "",
+        deprecated: None,
+        rename: None,
+      }
+    });
+  }
+
+  Ok(quote::quote! {
+    #enum_
+
+    impl deno_bindgen::FromHandle for #ty_str {
+        type Raw = *const ();
+
+        #[allow(unused_mut, unused_variables)]
+        unsafe fn from_handle(ptr: *const ()) -> Self {
+            let (tag, mut fields) = deno_bindgen::from_tagged(ptr);
+            match tag.as_str() {
+                #(#decode)*
+                _ => panic!("invalid variant {} for {}", tag, stringify!(#ty_str)),
+            }
+        }
+    }
+
+    impl deno_bindgen::IntoHandle for #ty_str {
+        type Raw = *const ();
+
+        fn into_handle(self) -> *const () {
+            let value = match self {
+                #(#encode)*
+            };
+            deno_bindgen::into_json(&value)
+        }
+    }
+
+    const _: () = {
+      #[deno_bindgen::linkme::distributed_slice(deno_bindgen::INVENTORY)]
+      pub static _A: deno_bindgen::Inventory = deno_bindgen::Inventory::Union(
+        deno_bindgen::inventory::Union {
+          name: stringify!(#ty_str),
+          variants: &[#(#variants),*],
+          rename: #rename,
+        }
+      );
+    };
+  })
+}
@@ -1,23 +1,35 @@
+use deno_bindgen_ir::Callback;
 use deno_bindgen_ir::SymbolBuilder;
 use deno_bindgen_ir::Type;
 use proc_macro2::Ident;
 use proc_macro2::Span;
 use proc_macro2::TokenStream as TokenStream2;
+use syn::ext::IdentExt;
 use syn::parse_quote;
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::token::Comma;
+use syn::AngleBracketedGenericArguments;
+use syn::BareFnArg;
 use syn::FnArg;
+use syn::GenericArgument;
 use syn::ItemFn;
 use syn::PatType;
+use syn::PathArguments;
 use syn::ReturnType;
+use syn::TraitBound;
+use syn::TypeBareFn;
+use syn::TypeImplTrait;
+use syn::TypeParamBound;
 use syn::TypePath;
 use syn::TypePtr;
 use syn::TypeReference;
 use syn::TypeSlice;
+use syn::TypeTuple;
 
 use crate::util::Error;
 use crate::util::Result;
+use crate::util::{self};
 use crate::FnAttributes;
 
 fn custom_type(ty: &str) -> Type {
@@ -25,11 +37,65 @@ fn custom_type(ty: &str) -> Type {
   Type::CustomType(Box::leak(ty.to_string().into_boxed_str()))
 }
 
-fn parse_type(ty: &Box<syn::Type>) -> Result<Type> {
-  match **ty {
+pub(crate) fn parse_type(ty: &syn::Type) -> Result<Type> {
+  match *ty {
     syn::Type::Path(TypePath { ref path, .. }) => {
+      if let Some(segment) = path.segments.last() {
+        if segment.ident == "Result" {
+          // `Result<T, E>` or an alias like `io::Result<T>`.
+          let ty = match generic_argument(&segment.arguments) {
+            Some(ty) => parse_type(ty)?,
+            None => return Err(Error::UnsupportedType),
+          };
+          if ty.is_fallible() {
+            return Err(Error::UnsupportedType);
+          }
+
+          return Ok(Type::Result(Box::leak(Box::new(ty))));
+        }
+
+        if segment.ident == "Option" {
+          let ty = match generic_argument(&segment.arguments) {
+            Some(ty) => parse_type(ty)?,
+            None => return Err(Error::UnsupportedType),
+          };
+          if matches!(
+            ty,
+            Type::Void
+              | Type::Pointer
+              | Type::Result(_)
+              | Type::Optional(_)
+              | Type::Function(_)
+          ) {
+            return Err(Error::UnsupportedType);
+          }
+
+          return Ok(Type::Optional(Box::leak(Box::new(ty))));
+        }
+
+        // `Vec<T>` or `Box<[T]>`.
+        let elem = match generic_argument(&segment.arguments) {
+          Some(ty) if segment.ident == "Vec" => Some(ty),
+          Some(syn::Type::Slice(TypeSlice { elem, .. }))
+            if segment.ident == "Box" =>
+          {
+            Some(&**elem)
+          }
+          _ => None,
+        };
+        if let Some(elem) = elem {
+          let ty = parse_type(elem)?;
+          if !is_typed_array(ty) {
+            return Err(Error::UnsupportedType);
+          }
+
+          return Ok(Type::Vec(Box::leak(Box::new(ty))));
+        }
+      }
+
       if let Some(ident) = path.get_ident() {
         match ident.to_string().as_str() {
+          "bool" => return Ok(Type::Bool),
           "u8" => return Ok(Type::Uint8),
           "u16" => return Ok(Type::Uint16),
           "u32" => return Ok(Type::Uint32),
@@ -40,8 +106,9 @@ fn parse_type(ty: &Box<syn::Type>) -> Result<Type> {
           "i64" => return Ok(Type::Int64),
           "f32" => return Ok(Type::Float32),
           "f64" => return Ok(Type::Float64),
-          "usize" => return Ok(Type::Uint64),
-          "isize" => return Ok(Type::Int64),
+          "usize" => return Ok(Type::Usize),
+          "isize" => return Ok(Type::Isize),
+          "String" => return Ok(Type::String),
           ty_str => {
             return Ok(custom_type(ty_str));
           }
@@ -52,54 +119,179 @@ fn parse_type(ty: &Box<syn::Type>) -> Result<Type> {
     }
     syn::Type::Reference(TypeReference { ref elem, .. }) => {
       if let syn::Type::Slice(TypeSlice { ref elem, .. }) = *elem.as_ref() {
-        if parse_type(elem)?.is_number() {
-          return Ok(Type::Buffer);
+        let ty = parse_type(elem)?;
+        if is_typed_array(ty) {
+          return Ok(Type::Buffer(Box::leak(Box::new(ty))));
         }
       }
 
       if let syn::Type::Path(TypePath { ref path, .. }) = *elem.as_ref() {
         if let Some(ident) = path.get_ident() {
-          let ref ty_str = ident.to_string();
-          return Ok(custom_type(ty_str));
+          if ident == "str" {
+            return Ok(Type::Str);
+          }
+
+          return Ok(custom_type(&ident.to_string()));
         }
       }
 
       Err(Error::UnsupportedType)
     }
 
+    syn::Type::BareFn(TypeBareFn {
+      ref abi,
+      ref inputs,
+      ref output,
+      ..
+    }) => {
+      // Only the C ABI can be called back from Deno.
+      match abi {
+        Some(abi)
+          if abi.name.as_ref().is_none_or(|name| name.value() == "C") => {}
+        _ => return Err(Error::UnsupportedType),
+      }
+
+      let inputs = inputs.iter().map(|BareFnArg { ty, .. }| ty);
+      parse_callback(inputs, output, false)
+    }
+    syn::Type::ImplTrait(TypeImplTrait { ref bounds, .. }) => {
+      for bound in bounds {
+        let TypeParamBound::Trait(TraitBound { path, .. }) = bound else {
+          continue;
+        };
+        let Some(segment) = path.segments.last() else {
+          continue;
+        };
+        if let PathArguments::Parenthesized(ref args) = segment.arguments {
+          if matches!(
+            segment.ident.to_string().as_str(),
+            "Fn" | "FnMut" | "FnOnce"
+          ) {
+            return parse_callback(args.inputs.iter(), &args.output, true);
+          }
+        }
+      }
+
+      Err(Error::UnsupportedType)
+    }
     syn::Type::Ptr(TypePtr { .. }) => Ok(Type::Pointer),
+    syn::Type::Tuple(TypeTuple { ref elems, .. }) if elems.is_empty() => {
+      Ok(Type::Void)
+    }
     _ => Err(Error::UnsupportedType),
   }
 }
 
+/// Callbacks only take and return values Deno can pass as is.
+fn parse_callback<'a>(
+  inputs: impl Iterator<Item = &'a syn::Type>,
+  output: &ReturnType,
+  closure: bool,
+) -> Result<Type> {
+  let mut parameters = Vec::new();
+  for ty in inputs {
+    let ty = parse_type(ty)?;
+    if !ty.is_scalar() && ty != Type::Pointer {
+      return Err(Error::UnsupportedType);
+    }
+    parameters.push(ty);
+  }
+
+  let result = match output {
+    ReturnType::Default => Type::Void,
+    ReturnType::Type(_, ty) => parse_type(ty)?,
+  };
+  if !result.is_scalar() && !matches!(result, Type::Void | Type::Pointer) {
+    return Err(Error::UnsupportedType);
+  }
+
+  Ok(Type::Function(Box::leak(Box::new(Callback {
+    parameters: Box::leak(parameters.into_boxed_slice()),
+    result,
+    closure,
+    stored: false,
+  }))))
+}
+
+/// Element types with a matching typed array.
+fn is_typed_array(ty: Type) -> bool {
+  // There are no typed arrays of pointer-sized integers.
+  ty.is_number() && !matches!(ty, Type::Usize | Type::Isize)
+}
+
+/// Types that can be returned but not taken as parameters.
+pub(crate) fn is_return_only(ty: Type) -> bool {
+  match ty {
+    Type::Result(_) | Type::Vec(_) => true,
+    Type::Optional(ty) => is_return_only(*ty),
+    _ => false,
+  }
+}
+
+pub(crate) fn is_borrowed(ty: Type) -> bool {
+  match ty {
+    // Callbacks are closed once the call returns.
+    Type::Buffer(_) | Type::Str | Type::Function(_) => true,
+    Type::Result(ty) | Type::Optional(ty) => is_borrowed(*ty),
+    _ => false,
+  }
+}
+
+fn generic_argument(arguments: &PathArguments) -> Option<&syn::Type> {
+  match arguments {
+    PathArguments::AngleBracketed(AngleBracketedGenericArguments {
+      args,
+      ..
+    }) => match args.first()? {
+      GenericArgument::Type(ty) => Some(ty),
+      _ => None,
+    },
+    _ => None,
+  }
+}
+
 pub(crate) fn handle_inner(
   fn_: ItemFn,
   attrs: FnAttributes,
 ) -> Result<(TokenStream2, SymbolBuilder)> {
-  if fn_.sig.asyncness.is_some() {
-    return Err(Error::Asyncness);
-  }
-
   if fn_.sig.receiver().is_some() {
     return Err(Error::Reciever);
   }
 
   // TODO: check right ABI
 
+  let is_async = fn_.sig.asyncness.is_some();
+
   let mut ffi_fn = fn_.clone();
+  ffi_fn.sig.asyncness = None;
   ffi_fn.sig.abi.get_or_insert_with(|| {
     parse_quote!(
         extern "C"
     )
   });
 
+  let deprecated = util::deprecated(&fn_.attrs);
+  if deprecated.is_some() {
+    // Only the bindings are deprecated, calling the function is fine.
+    ffi_fn
+      .attrs
+      .retain(|attr| !attr.path().is_ident("deprecated"));
+    ffi_fn.attrs.push(parse_quote!(#[allow(deprecated)]));
+  }
+
   let mut inputs: Punctuated<FnArg, Comma> = Punctuated::new();
   let mut transforms: Vec<TokenStream2> = Vec::new();
 
   let mut symbol = SymbolBuilder::new(fn_.sig.ident.clone());
-  symbol.non_blocking(attrs.non_blocking);
+  // Futures are driven to completion off the JavaScript thread.
+  symbol.non_blocking(attrs.non_blocking || is_async);
   symbol.internal(attrs.internal);
   symbol.is_constructor(attrs.constructor);
+  symbol.is_static(attrs.is_static);
+  symbol.int64(attrs.int64);
+  symbol.docs(util::docs(&fn_.attrs));
+  symbol.deprecated(deprecated);
+  symbol.rename(attrs.rename);
 
   // Cannot use enumerate here, there can be multiple raw args per type.
   let mut i = 0;
@@ -111,8 +303,23 @@ pub(crate) fn handle_inner(
         ref mut ty,
         ..
       }) => {
-        let ty = parse_type(ty)?;
-        symbol.push(ty);
+        let ty = match parse_type(ty)? {
+          Type::Function(callback) if attrs.stores_callbacks => {
+            Type::Function(Box::leak(Box::new(Callback {
+              stored: true,
+              ..*callback
+            })))
+          }
+          ty => ty,
+        };
+        if is_return_only(ty) {
+          return Err(Error::UnsupportedType);
+        }
+        let name = match **pat {
+          syn::Pat::Ident(ref ident) => ident.ident.unraw().to_string(),
+          _ => unreachable!(),
+        };
+        symbol.push(name, ty);
 
         const X_ARG_PREFIX: &str = "__arg_";
         // Divide the type into its raw components.
@@ -136,7 +343,7 @@ pub(crate) fn handle_inner(
         }
 
         // Simple type.
-        if raw_ty.len() == 0 {
+        if raw_ty.is_empty() {
           inputs.push(arg.clone());
           i += 1;
         }
@@ -157,6 +364,12 @@ pub(crate) fn handle_inner(
     ReturnType::Default => {}
     ReturnType::Type(_, ref mut ty) => {
       let t = parse_type(ty)?;
+      // Borrowed data cannot outlive the call, unless it is a slice viewed
+      // for as long as the object it belongs to.
+      let view = attrs.borrows_self && matches!(t, Type::Buffer(_));
+      if is_borrowed(t) && !view {
+        return Err(Error::UnsupportedType);
+      }
 
       if let Some(transform) =
         t.apply_ret_transform(&mut ret, ret_ident.clone())
@@ -169,6 +382,12 @@ pub(crate) fn handle_inner(
     }
   }
 
+  // Errors and panics are reported through a trailing out parameter.
+  let error = Type::error_slot();
+  inputs.push(parse_quote!(
+    #error: *mut *mut deno_bindgen::OwnedBuffer
+  ));
+
   let idents = ffi_fn
     .sig
     .inputs
@@ -185,14 +404,26 @@ pub(crate) fn handle_inner(
   let name = fn_.sig.ident.clone();
   ffi_fn.sig.inputs = inputs;
 
+  let call = if let Some(asyncness) = fn_.sig.asyncness {
+    quote::quote_spanned! { asyncness.span=>
+      deno_bindgen::__block_on!(#name(#(#idents),*))
+    }
+  } else {
+    quote::quote! { #name(#(#idents),*) }
+  };
+
   ffi_fn.block = parse_quote!({
       #fn_
 
-      #(#transforms)*
+      unsafe {
+        deno_bindgen::catch_unwind(#error, move || {
+          #(#transforms)*
 
-      let #ret_ident = #name(#(#idents),*);
-      #ret_transform
-      #ret_ident
+          let #ret_ident = #call;
+          #ret_transform
+          #ret_ident
+        })
+      }
   });
 
   Ok((
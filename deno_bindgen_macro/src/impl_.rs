@@ -1,12 +1,19 @@
+use proc_macro2::Group;
+use proc_macro2::Ident;
 use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::TokenTree;
 use quote::format_ident;
+use quote::ToTokens;
 use syn::parse_quote;
 use syn::punctuated::Punctuated;
+use syn::Attribute;
 use syn::ImplItemFn;
 use syn::ItemImpl;
+use syn::ReturnType;
 
 use crate::util::Result;
 use crate::util::{self};
+use crate::FnAttributes;
 
 pub fn handle(mut impl_: ItemImpl) -> Result<TokenStream2> {
   if impl_.generics.params.first().is_some() {
@@ -22,85 +29,119 @@ pub fn handle(mut impl_: ItemImpl) -> Result<TokenStream2> {
     _ => return Err(util::Error::UnsupportedType),
   };
 
-  let ref ty_str @ _ = self_ty.get_ident().unwrap();
+  let ty_str = self_ty.get_ident().unwrap();
 
   let mut methods = Vec::new();
   let mut syms = Punctuated::<TokenStream2, syn::Token![,]>::new();
   for item in impl_.items.iter_mut() {
-    match item {
-      syn::ImplItem::Fn(ImplItemFn { sig, attrs, .. }) => {
-        let mut is_constructor = false;
-        if let Some(attr) = attrs.first() {
-          let path = attr.path();
-          is_constructor = path.is_ident("constructor");
-
-          attrs.clear();
-        }
+    if let syn::ImplItem::Fn(ImplItemFn { sig, attrs, .. }) = item {
+      if sig.asyncness.is_some() {
+        return Err(util::Error::Asyncness);
+      }
 
-        // TODO: Add common name magling util.
-        let method_name = sig.ident.clone();
-        let mangled_name = format_ident!("__{}_{}", ty_str, method_name);
-        // ...
-        let ref out = sig.output;
-        let inputs = sig.inputs.iter();
-
-        fn idents_with_skip<'a>(
-          arg: syn::punctuated::Iter<'a, syn::FnArg>,
-          skip: usize,
-        ) -> Vec<&'a syn::Ident> {
-          arg
-            .skip(skip)
-            .map(|arg| match arg {
-              syn::FnArg::Receiver(_) => unreachable!(),
-              syn::FnArg::Typed(pat_type) => match &*pat_type.pat {
-                syn::Pat::Ident(ident) => &ident.ident,
-                _ => unreachable!(),
-              },
-            })
-            .collect::<Vec<_>>()
+      let is_constructor =
+        attrs.iter().any(|attr| attr.path().is_ident("constructor"));
+      attrs.retain(|attr| !attr.path().is_ident("constructor"));
+      let method_attrs = method_attributes(attrs)?;
+
+      // Carried over to the generated docs.
+      let docs = attrs
+        .iter()
+        .filter(|attr| {
+          attr.path().is_ident("doc") || attr.path().is_ident("deprecated")
+        })
+        .collect::<Vec<_>>();
+
+      // TODO: Add common name magling util.
+      let method_name = sig.ident.clone();
+      let mangled_name = format_ident!("__{}_{}", ty_str, method_name);
+      // Borrowed slices are tied to the handle rather than a Rust lifetime.
+      let mut output = sig.output.clone();
+      if let ReturnType::Type(_, ref mut ty) = output {
+        if let syn::Type::Reference(ref mut reference) = **ty {
+          if let syn::Type::Slice(_) = *reference.elem {
+            reference.lifetime = Some(parse_quote!('static));
+          }
         }
+      }
+      // `Self` does not resolve outside of the impl block.
+      let out = replace_self(output.to_token_stream(), ty_str);
+      let inputs = sig.inputs.iter();
+
+      fn idents_with_skip<'a>(
+        arg: syn::punctuated::Iter<'a, syn::FnArg>,
+        skip: usize,
+      ) -> Vec<&'a syn::Ident> {
+        arg
+          .skip(skip)
+          .map(|arg| match arg {
+            syn::FnArg::Receiver(_) => unreachable!(),
+            syn::FnArg::Typed(pat_type) => match &*pat_type.pat {
+              syn::Pat::Ident(ident) => &ident.ident,
+              _ => unreachable!(),
+            },
+          })
+          .collect::<Vec<_>>()
+      }
 
-        let method = if sig.receiver().is_some() {
-          let idents = idents_with_skip(inputs.clone(), 1);
-          // First argument is the receiver, we skip it.
-          let inputs = inputs.skip(1);
-
-          parse_quote! {
-            #[allow(non_snake_case)]
-            fn #mangled_name (self_: *mut #ty_str, #(#inputs),*) #out {
-              let self_ = unsafe { &mut *self_ };
-              self_. #method_name (#(#idents),*)
-            }
-          }
-        } else if is_constructor {
-          let idents = idents_with_skip(inputs.clone(), 0);
-          parse_quote!(
-            #[allow(non_snake_case)]
-            fn #mangled_name (#(#inputs),*) #out {
-              #ty_str:: #method_name (#(#idents),*)
-            }
-          )
+      // `self` by value takes the object out of its handle.
+      let consumes_self = sig
+        .receiver()
+        .is_some_and(|receiver| receiver.reference.is_none());
+
+      let method = if let Some(receiver) = sig.receiver() {
+        let idents = idents_with_skip(inputs.clone(), 1);
+        // First argument is the receiver, we skip it.
+        let inputs = inputs
+          .skip(1)
+          .map(|arg| replace_self(arg.to_token_stream(), ty_str));
+        let self_ = if receiver.reference.is_some() {
+          quote::quote! { deno_bindgen::handle_mut::<#ty_str>(self_) }
         } else {
-          return Err(util::Error::MissingReceiver);
+          quote::quote! { deno_bindgen::take_handle::<#ty_str>(self_) }
         };
 
-        let (generated, mut sym) = crate::fn_::handle_inner(
-          method,
-          crate::FnAttributes {
-            internal: true,
-            constructor: is_constructor,
-            ..Default::default()
-          },
-        )?;
-
-        // Set method name to the original name as the
-        // managed name is used for the internal symbol.
-        sym.set_name(method_name);
-
-        methods.push(generated);
-        syms.push(quote::quote! { #sym });
-      }
-      _ => {}
+        parse_quote! {
+          #(#docs)*
+          #[allow(non_snake_case, deprecated)]
+          fn #mangled_name (self_: *const (), #(#inputs),*) #out {
+            let self_ = unsafe { #self_ };
+            self_. #method_name (#(#idents),*)
+          }
+        }
+      } else {
+        let idents = idents_with_skip(inputs.clone(), 0);
+        let inputs =
+          inputs.map(|arg| replace_self(arg.to_token_stream(), ty_str));
+        parse_quote!(
+          #(#docs)*
+          #[allow(non_snake_case, deprecated)]
+          fn #mangled_name (#(#inputs),*) #out {
+            #ty_str:: #method_name (#(#idents),*)
+          }
+        )
+      };
+
+      let (generated, mut sym) = crate::fn_::handle_inner(
+        method,
+        FnAttributes {
+          internal: true,
+          constructor: is_constructor,
+          is_static: sig.receiver().is_none() && !is_constructor,
+          borrows_self: sig
+            .receiver()
+            .is_some_and(|receiver| receiver.reference.is_some()),
+          ..method_attrs
+        },
+      )?;
+
+      // Set method name to the original name as the
+      // managed name is used for the internal symbol.
+      sym.set_name(method_name);
+      sym.consumes_self(consumes_self);
+
+      methods.push(generated);
+      syms.push(quote::quote! { #sym });
     }
   }
 
@@ -108,23 +149,21 @@ pub fn handle(mut impl_: ItemImpl) -> Result<TokenStream2> {
   {
     let ident = format_ident!("__{}_dealloc", ty_str);
     let dispose = parse_quote! {
-      #[allow(non_snake_case)]
-      fn #ident(self_: *mut #ty_str) {
-        if self_.is_null() {
-          return;
-        }
-        unsafe { drop(Box::from_raw(self_)) }
+      #[allow(non_snake_case, deprecated)]
+      fn #ident(self_: *const ()) {
+        unsafe { deno_bindgen::drop_handle::<#ty_str>(self_) }
       }
     };
     let (generated, mut sym) = crate::fn_::handle_inner(
       dispose,
-      crate::FnAttributes {
+      FnAttributes {
         internal: true,
         ..Default::default()
       },
     )?;
 
     sym.set_name(format_ident!("dealloc"));
+    sym.consumes_self(true);
 
     methods.push(generated);
     syms.push(quote::quote! { #sym });
@@ -133,6 +172,7 @@ pub fn handle(mut impl_: ItemImpl) -> Result<TokenStream2> {
   Ok(quote::quote! {
     #impl_
     #(#methods)*
+    #[allow(deprecated)]
     const _: () = {
       // Assert that the type implements `BindgenType`.
       const fn _assert_impl<T: ::deno_bindgen::BindgenType>() {}
@@ -143,8 +183,55 @@ pub fn handle(mut impl_: ItemImpl) -> Result<TokenStream2> {
         deno_bindgen::inventory::Struct {
           name: stringify!(#ty_str),
           methods: &[#syms],
+          docs: <#ty_str as ::deno_bindgen::BindgenType>::DOCS,
+          deprecated: <#ty_str as ::deno_bindgen::BindgenType>::DEPRECATED,
+          rename: <#ty_str as ::deno_bindgen::BindgenType>::RENAME,
+          finalize: <#ty_str as ::deno_bindgen::BindgenType>::FINALIZE,
         }
       );
     };
   })
 }
+
+/// Replaces `Self` with the implementing type.
+fn replace_self(tokens: TokenStream2, ty: &Ident) -> TokenStream2 {
+  tokens
+    .into_iter()
+    .map(|tt| match tt {
+      TokenTree::Ident(ident) if ident == "Self" => {
+        TokenTree::Ident(Ident::new(&ty.to_string(), ident.span()))
+      }
+      TokenTree::Group(group) => {
+        let mut replaced =
+          Group::new(group.delimiter(), replace_self(group.stream(), ty));
+        replaced.set_span(group.span());
+        TokenTree::Group(replaced)
+      }
+      tt => tt,
+    })
+    .collect()
+}
+
+/// Strips `#[deno_bindgen(...)]` from a method, returning its options.
+/// Methods are always called on the JavaScript thread.
+fn method_attributes(attrs: &mut Vec<Attribute>) -> Result<FnAttributes> {
+  let mut method_attrs = FnAttributes::default();
+  let mut result = Ok(());
+  attrs.retain(|attr| {
+    if !attr.path().is_ident("deno_bindgen") {
+      return true;
+    }
+
+    if attr
+      .parse_nested_meta(|meta| method_attrs.parse(meta))
+      .is_err()
+    {
+      result = Err(util::Error::Attribute);
+    }
+    false
+  });
+  if method_attrs.non_blocking {
+    return Err(util::Error::Attribute);
+  }
+  result.map(|_| method_attrs)
+}
@@ -1,11 +1,14 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
 
+use deno_bindgen_ir::Int64Mode;
 use proc_macro::TokenStream;
 use syn::meta::ParseNestedMeta;
 use syn::parse2;
 use syn::parse_macro_input;
 use syn::Item;
+use syn::LitStr;
 
+mod enum_;
 mod fn_;
 mod impl_;
 mod struct_;
@@ -15,15 +18,89 @@ mod util;
 pub(crate) struct FnAttributes {
   pub(crate) non_blocking: bool,
   pub(crate) constructor: bool,
+  pub(crate) rename: Option<String>,
+  pub(crate) int64: Int64Mode,
+  /// Callbacks may be kept by Rust after the call returns.
+  pub(crate) stores_callbacks: bool,
 
   pub(crate) internal: bool,
+  pub(crate) is_static: bool,
+  /// Methods taking `&self` or `&mut self` may return slices borrowed from
+  /// the object.
+  pub(crate) borrows_self: bool,
 }
 
 impl FnAttributes {
-  fn parse(&mut self, meta: ParseNestedMeta) -> syn::parse::Result<()> {
+  pub(crate) fn parse(
+    &mut self,
+    meta: ParseNestedMeta,
+  ) -> syn::parse::Result<()> {
     if meta.path.is_ident("non_blocking") {
       self.non_blocking = true;
       Ok(())
+    } else if meta.path.is_ident("rename") {
+      self.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+      Ok(())
+    } else if meta.path.is_ident("stores_callbacks") {
+      self.stores_callbacks = true;
+      Ok(())
+    } else if meta.path.is_ident("int64") {
+      let mode = meta.value()?.parse::<LitStr>()?;
+      self.int64 = match mode.value().as_str() {
+        "bigint" => Int64Mode::BigInt,
+        "number" => Int64Mode::Number,
+        _ => {
+          return Err(syn::Error::new(
+            mode.span(),
+            "expected \"bigint\" or \"number\"",
+          ))
+        }
+      };
+      Ok(())
+    } else {
+      Err(meta.error("unsupported attribute"))
+    }
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct StructAttributes {
+  pub(crate) serde: bool,
+  pub(crate) rename: Option<String>,
+  pub(crate) finalize: bool,
+  pub(crate) repr_c: bool,
+}
+
+impl StructAttributes {
+  fn parse(&mut self, meta: ParseNestedMeta) -> syn::parse::Result<()> {
+    if meta.path.is_ident("serde") {
+      self.serde = true;
+      Ok(())
+    } else if meta.path.is_ident("finalize") {
+      self.finalize = true;
+      Ok(())
+    } else if meta.path.is_ident("repr_c") {
+      self.repr_c = true;
+      Ok(())
+    } else if meta.path.is_ident("rename") {
+      self.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+      Ok(())
+    } else {
+      Err(meta.error("unsupported attribute"))
+    }
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct EnumAttributes {
+  pub(crate) rename: Option<String>,
+}
+
+impl EnumAttributes {
+  fn parse(&mut self, meta: ParseNestedMeta) -> syn::parse::Result<()> {
+    if meta.path.is_ident("rename") {
+      self.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+      Ok(())
     } else {
       Err(meta.error("unsupported attribute"))
     }
@@ -40,8 +117,27 @@ pub fn deno_bindgen(args: TokenStream, input: TokenStream) -> TokenStream {
 
       fn_::handle(input, attrs).unwrap().into()
     }
-    Item::Struct(input) => struct_::handle(input).unwrap().into(),
-    Item::Impl(input) => impl_::handle(input).unwrap().into(),
+    Item::Struct(input) => {
+      let mut attrs = StructAttributes::default();
+      let attrs_parser = syn::meta::parser(|meta| attrs.parse(meta));
+      parse_macro_input!(args with attrs_parser);
+
+      struct_::handle(input, attrs).unwrap().into()
+    }
+    Item::Impl(input) => {
+      let attrs_parser =
+        syn::meta::parser(|meta| Err(meta.error("unsupported attribute")));
+      parse_macro_input!(args with attrs_parser);
+
+      impl_::handle(input).unwrap().into()
+    }
+    Item::Enum(input) => {
+      let mut attrs = EnumAttributes::default();
+      let attrs_parser = syn::meta::parser(|meta| attrs.parse(meta));
+      parse_macro_input!(args with attrs_parser);
+
+      enum_::handle(input, attrs).unwrap().into()
+    }
     _ => panic!("only functions are supported"),
   }
 }
@@ -1,10 +1,29 @@
+use deno_bindgen_ir::Type;
+use inflector::Inflector;
 use proc_macro2::TokenStream as TokenStream2;
+use quote::format_ident;
+use syn::ext::IdentExt;
+use syn::parse_quote;
+use syn::spanned::Spanned;
+use syn::Attribute;
+use syn::Fields;
 use syn::ItemStruct;
+use syn::PathArguments;
+use syn::TypeArray;
+use syn::TypePath;
+use syn::TypeReference;
+use syn::TypeSlice;
+use syn::TypeTuple;
+use syn::Visibility;
 
 use crate::util::Result;
 use crate::util::{self};
+use crate::StructAttributes;
 
-pub fn handle(struct_: ItemStruct) -> Result<TokenStream2> {
+pub fn handle(
+  mut struct_: ItemStruct,
+  attrs: StructAttributes,
+) -> Result<TokenStream2> {
   if struct_.generics.params.first().is_some() {
     return Err(util::Error::Generics);
   }
@@ -13,14 +32,511 @@ pub fn handle(struct_: ItemStruct) -> Result<TokenStream2> {
     return Err(util::Error::WhereClause);
   }
 
-  let ref ty_str @ _ = struct_.ident;
+  let rename = match attrs.rename {
+    Some(ref rename) => quote::quote! { Some(#rename) },
+    None => quote::quote! { None },
+  };
+  let docs = util::docs(&struct_.attrs);
+  let deprecated = match util::deprecated(&struct_.attrs) {
+    Some(ref note) => quote::quote! { Some(#note) },
+    None => quote::quote! { None },
+  };
+
+  if attrs.repr_c {
+    // Passed by value, neither freed nor encoded as JSON.
+    if attrs.finalize || attrs.serde {
+      return Err(util::Error::Attribute);
+    }
+    return handle_repr_c(struct_, rename, docs, deprecated);
+  }
+
+  if attrs.serde {
+    // Interfaces are plain values, there is nothing to free.
+    if attrs.finalize {
+      return Err(util::Error::Attribute);
+    }
+    return handle_serde(struct_, rename, docs, deprecated);
+  }
+
+  let properties = handle_properties(&mut struct_)?;
+
+  let ty_str = &struct_.ident;
+  let finalize = attrs.finalize;
   Ok(quote::quote! {
     #struct_
+    #properties
 
+    #[allow(deprecated)]
     impl ::deno_bindgen::BindgenType for #ty_str {
+        const DOCS: &'static str = #docs;
+        const DEPRECATED: Option<&'static str> = #deprecated;
+        const RENAME: Option<&'static str> = #rename;
+        const FINALIZE: bool = #finalize;
+
         fn type_name() -> &'static str {
             stringify!(#ty_str)
         }
     }
+
+    #[allow(deprecated)]
+    impl ::deno_bindgen::IntoHandle for #ty_str {
+        type Raw = *const ();
+
+        fn into_handle(self) -> *const () {
+            ::deno_bindgen::new_handle(self)
+        }
+    }
   })
 }
+
+/// Accessors for the `pub` fields of a class. Only numbers, `bool` and
+/// `String` are picked up by type. Fieldless enums can't be told apart from
+/// other types, so their fields are opted in with
+/// `#[deno_bindgen(property)]`, which asserts `FieldlessEnum` on the field
+/// type. Other fields, and those marked
+/// `#[deno_bindgen(skip)]`, stay private.
+fn handle_properties(struct_: &mut ItemStruct) -> Result<TokenStream2> {
+  let ty_str = struct_.ident.clone();
+  let Fields::Named(ref mut named) = struct_.fields else {
+    return Ok(TokenStream2::new());
+  };
+
+  let mut symbols = Vec::new();
+  let mut properties = Vec::new();
+  for field in named.named.iter_mut() {
+    let attr = field_attr(&mut field.attrs)?;
+    if attr == Some(FieldAttr::Skip)
+      || !matches!(field.vis, Visibility::Public(_))
+    {
+      continue;
+    }
+
+    let ty = match (crate::fn_::parse_type(&field.ty), attr) {
+      (Ok(ty @ Type::CustomType(_)), Some(FieldAttr::Property)) => ty,
+      (_, Some(FieldAttr::Property)) => return Err(util::Error::Property),
+      (Ok(ty), _) if ty.is_scalar() || matches!(ty, Type::String) => ty,
+      _ => continue,
+    };
+
+    let ident = field.ident.as_ref().unwrap();
+    let field_ty = &field.ty;
+    if attr == Some(FieldAttr::Property) {
+      symbols.push(quote::quote_spanned! { field_ty.span()=>
+        const _: () = {
+          const fn _assert_fieldless<T: ::deno_bindgen::FieldlessEnum>() {}
+          _assert_fieldless::<#field_ty>();
+        };
+      });
+    }
+    let name = ident.unraw().to_string();
+    let getter = format_ident!("__{}__get_{}", ty_str, name);
+    let setter = format_ident!("__{}__set_{}", ty_str, name);
+    for accessor in [
+      parse_quote! {
+        #[allow(non_snake_case, deprecated)]
+        fn #getter(self_: *const ()) -> #field_ty {
+          let self_ = unsafe { deno_bindgen::handle_mut::<#ty_str>(self_) };
+          ::std::clone::Clone::clone(&self_.#ident)
+        }
+      },
+      parse_quote! {
+        #[allow(non_snake_case, deprecated)]
+        fn #setter(self_: *const (), value: #field_ty) {
+          let self_ = unsafe { deno_bindgen::handle_mut::<#ty_str>(self_) };
+          self_.#ident = value;
+        }
+      },
+    ] {
+      let (generated, _) = crate::fn_::handle_inner(
+        accessor,
+        crate::FnAttributes {
+          internal: true,
+          ..Default::default()
+        },
+      )?;
+      symbols.push(generated);
+    }
+
+    let ty = ty.to_ident();
+    properties.push(quote::quote! {
+      deno_bindgen::inventory::Property { name: #name, ty: #ty }
+    });
+  }
+
+  if properties.is_empty() {
+    return Ok(TokenStream2::new());
+  }
+
+  Ok(quote::quote! {
+    #(#symbols)*
+
+    const _: () = {
+      #[deno_bindgen::linkme::distributed_slice(deno_bindgen::INVENTORY)]
+      pub static _A: deno_bindgen::Inventory = deno_bindgen::Inventory::Properties(
+        deno_bindgen::inventory::Properties {
+          name: stringify!(#ty_str),
+          properties: &[#(#properties),*],
+        }
+      );
+    };
+  })
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum FieldAttr {
+  Skip,
+  Property,
+}
+
+/// Strips `#[deno_bindgen(skip)]` or `#[deno_bindgen(property)]` from a
+/// field, returning which one was present.
+fn field_attr(attrs: &mut Vec<Attribute>) -> Result<Option<FieldAttr>> {
+  let mut found = None;
+  let mut result = Ok(());
+  attrs.retain(|attr| {
+    if !attr.path().is_ident("deno_bindgen") {
+      return true;
+    }
+
+    let parsed = attr.parse_nested_meta(|meta| {
+      let attr = if meta.path.is_ident("skip") {
+        FieldAttr::Skip
+      } else if meta.path.is_ident("property") {
+        FieldAttr::Property
+      } else {
+        return Err(meta.error("unsupported attribute"));
+      };
+      match found.replace(attr) {
+        Some(other) if other != attr => {
+          Err(meta.error("conflicting attributes"))
+        }
+        _ => Ok(()),
+      }
+    });
+    if parsed.is_err() {
+      result = Err(util::Error::Attribute);
+    }
+    false
+  });
+  result.map(|_| found)
+}
+
+/// Structs passed by value as JSON, exported as a TypeScript interface.
+fn handle_serde(
+  struct_: ItemStruct,
+  ts_name: TokenStream2,
+  docs: String,
+  deprecated: TokenStream2,
+) -> Result<TokenStream2> {
+  let Fields::Named(ref named) = struct_.fields else {
+    return Err(util::Error::UnsupportedType);
+  };
+
+  let rename_all = serde_attrs(&struct_.attrs)
+    .into_iter()
+    .find_map(|(key, value)| (key == "rename_all").then_some(value?));
+
+  let mut fields = Vec::new();
+  for field in &named.named {
+    let mut name = field.ident.as_ref().unwrap().to_string();
+    if let Some(ref case) = rename_all {
+      name = rename(&name, case);
+    }
+
+    let mut skip = false;
+    for (key, value) in serde_attrs(&field.attrs) {
+      match (key.as_str(), value) {
+        ("rename", Some(value)) => name = value,
+        ("skip" | "skip_serializing", _) => skip = true,
+        _ => {}
+      }
+    }
+    if skip {
+      continue;
+    }
+
+    let ty = ts_type(&field.ty)?;
+    fields.push(quote::quote! {
+      deno_bindgen::inventory::Field { name: #name, ty: #ty }
+    });
+  }
+
+  let ty_str = &struct_.ident;
+  Ok(quote::quote! {
+    #struct_
+
+    #[allow(deprecated)]
+    impl deno_bindgen::FromHandle for #ty_str {
+        type Raw = *const ();
+
+        unsafe fn from_handle(ptr: *const ()) -> Self {
+            deno_bindgen::from_json(ptr)
+        }
+    }
+
+    #[allow(deprecated)]
+    impl deno_bindgen::IntoHandle for #ty_str {
+        type Raw = *const ();
+
+        fn into_handle(self) -> *const () {
+            deno_bindgen::into_json(&self)
+        }
+    }
+
+    #[allow(deprecated)]
+    const _: () = {
+      #[deno_bindgen::linkme::distributed_slice(deno_bindgen::INVENTORY)]
+      pub static _A: deno_bindgen::Inventory = deno_bindgen::Inventory::Interface(
+        deno_bindgen::inventory::Interface {
+          name: stringify!(#ty_str),
+          fields: &[#(#fields),*],
+          docs: #docs,
+          deprecated: #deprecated,
+          rename: #ts_name,
+        }
+      );
+    };
+  })
+}
+
+/// Plain structs of numbers passed by value as Deno FFI structs, exported as
+/// a TypeScript interface.
+fn handle_repr_c(
+  mut struct_: ItemStruct,
+  ts_name: TokenStream2,
+  docs: String,
+  deprecated: TokenStream2,
+) -> Result<TokenStream2> {
+  // Deno lays out the struct like a C compiler would.
+  for attr in struct_
+    .attrs
+    .iter()
+    .filter(|attr| attr.path().is_ident("repr"))
+  {
+    let mut is_c = false;
+    attr
+      .parse_nested_meta(|meta| {
+        is_c = meta.path.is_ident("C");
+        Ok(())
+      })
+      .map_err(|_| util::Error::ReprC)?;
+    if !is_c {
+      return Err(util::Error::ReprC);
+    }
+  }
+  struct_.attrs.push(parse_quote!(#[repr(C)]));
+
+  let Fields::Named(ref named) = struct_.fields else {
+    return Err(util::Error::ReprC);
+  };
+
+  let ty_str = &struct_.ident;
+  let mut fields = Vec::new();
+  for field in &named.named {
+    let ty = match crate::fn_::parse_type(&field.ty) {
+      Ok(ty) if ty.is_scalar() => ty,
+      _ => return Err(util::Error::ReprC),
+    };
+
+    let ident = field.ident.as_ref().unwrap();
+    let name = ident.unraw().to_string();
+    let ty = ty.to_ident();
+    fields.push(quote::quote! {
+      deno_bindgen::inventory::CField {
+        name: #name,
+        ty: #ty,
+        offset: ::std::mem::offset_of!(#ty_str, #ident),
+      }
+    });
+  }
+
+  Ok(quote::quote! {
+    #struct_
+
+    #[allow(deprecated)]
+    impl deno_bindgen::FromHandle for #ty_str {
+        type Raw = Self;
+
+        unsafe fn from_handle(raw: Self) -> Self {
+            raw
+        }
+    }
+
+    #[allow(deprecated)]
+    impl deno_bindgen::IntoHandle for #ty_str {
+        type Raw = Self;
+
+        fn into_handle(self) -> Self {
+            self
+        }
+    }
+
+    #[allow(deprecated)]
+    const _: () = {
+      #[deno_bindgen::linkme::distributed_slice(deno_bindgen::INVENTORY)]
+      pub static _A: deno_bindgen::Inventory = deno_bindgen::Inventory::CStruct(
+        deno_bindgen::inventory::CStruct {
+          name: stringify!(#ty_str),
+          fields: &[#(#fields),*],
+          size: ::std::mem::size_of::<#ty_str>(),
+          align: ::std::mem::align_of::<#ty_str>(),
+          docs: #docs,
+          deprecated: #deprecated,
+          rename: #ts_name,
+        }
+      );
+    };
+  })
+}
+
+/// The `#[serde(...)]` options that change the shape of the JSON.
+fn serde_attrs(attrs: &[Attribute]) -> Vec<(String, Option<String>)> {
+  let mut options = Vec::new();
+  for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+    // Anything we fail to parse is left for serde to report.
+    let _ = attr.parse_nested_meta(|meta| {
+      let key = match meta.path.get_ident() {
+        Some(ident) => ident.to_string(),
+        None => String::new(),
+      };
+      if meta.input.peek(syn::Token![=]) {
+        let value = meta.value()?.parse::<syn::Lit>()?;
+        let value = match value {
+          syn::Lit::Str(value) => Some(value.value()),
+          _ => None,
+        };
+        options.push((key, value));
+      } else if meta.input.peek(syn::token::Paren) {
+        // e.g. `rename(serialize = "...")`, these are ambiguous.
+        let content;
+        syn::parenthesized!(content in meta.input);
+        content.parse::<TokenStream2>()?;
+      } else {
+        options.push((key, None));
+      }
+      Ok(())
+    });
+  }
+  options
+}
+
+fn rename(name: &str, case: &str) -> String {
+  match case {
+    "lowercase" => name.to_lowercase(),
+    "UPPERCASE" => name.to_uppercase(),
+    "PascalCase" => name.to_pascal_case(),
+    "camelCase" => name.to_camel_case(),
+    "SCREAMING_SNAKE_CASE" => name.to_screaming_snake_case(),
+    "kebab-case" => name.to_kebab_case(),
+    "SCREAMING-KEBAB-CASE" => name.to_kebab_case().to_uppercase(),
+    _ => name.to_string(),
+  }
+}
+
+/// The TypeScript type of a value serialized by `serde_json`. Integers that
+/// don't fit in a `number` are rejected rather than silently rounded.
+pub(crate) fn ts_type(ty: &syn::Type) -> Result<String> {
+  let ty = match ty {
+    syn::Type::Path(TypePath { path, .. }) => {
+      let Some(segment) = path.segments.last() else {
+        return Ok("unknown".to_string());
+      };
+      let args = match &segment.arguments {
+        PathArguments::AngleBracketed(args) => args
+          .args
+          .iter()
+          .filter_map(|arg| match arg {
+            syn::GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+          })
+          .collect::<Vec<_>>(),
+        _ => Vec::new(),
+      };
+
+      match (segment.ident.to_string().as_str(), args.as_slice()) {
+        ("u8" | "u16" | "u32" | "i8" | "i16" | "i32" | "f32" | "f64", []) => {
+          "number".to_string()
+        }
+        ("u64" | "u128" | "usize" | "i64" | "i128" | "isize", []) => {
+          return Err(util::Error::JsonInt)
+        }
+        ("bool", []) => "boolean".to_string(),
+        ("String" | "str" | "char", []) => "string".to_string(),
+        ("Option", [ty]) => format!("{} | null", ts_type(ty)?),
+        ("Vec" | "VecDeque" | "HashSet" | "BTreeSet", [ty]) => {
+          array(ts_type(ty)?)
+        }
+        ("Box" | "Rc" | "Arc", [ty]) => ts_type(ty)?,
+        ("HashMap" | "BTreeMap", [_, ty]) => {
+          format!("Record<string, {}>", ts_type(ty)?)
+        }
+        // Other `#[deno_bindgen(serde)]` structs.
+        (name, []) => name.to_string(),
+        _ => "unknown".to_string(),
+      }
+    }
+    syn::Type::Reference(TypeReference { elem, .. }) => ts_type(elem)?,
+    syn::Type::Slice(TypeSlice { elem, .. })
+    | syn::Type::Array(TypeArray { elem, .. }) => array(ts_type(elem)?),
+    syn::Type::Tuple(TypeTuple { elems, .. }) if elems.is_empty() => {
+      "null".to_string()
+    }
+    syn::Type::Tuple(TypeTuple { elems, .. }) => format!(
+      "[{}]",
+      elems
+        .iter()
+        .map(ts_type)
+        .collect::<Result<Vec<_>>>()?
+        .join(", ")
+    ),
+    _ => "unknown".to_string(),
+  };
+  Ok(ty)
+}
+
+fn array(ty: String) -> String {
+  if ty.contains('|') {
+    format!("({ty})[]")
+  } else {
+    format!("{ty}[]")
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use syn::parse_quote;
+
+  use super::ts_type;
+
+  #[test]
+  fn test_ts_type() {
+    let cases: [(syn::Type, &str); 7] = [
+      (parse_quote!(u32), "number"),
+      (parse_quote!(String), "string"),
+      (parse_quote!(Option<Vec<bool>>), "boolean[] | null"),
+      (parse_quote!(Vec<Option<u8>>), "(number | null)[]"),
+      (
+        parse_quote!(HashMap<String, Point>),
+        "Record<string, Point>",
+      ),
+      (parse_quote!((f32, [u8; 4])), "[number, number[]]"),
+      (parse_quote!(&'static str), "string"),
+    ];
+    for (ty, expected) in cases {
+      assert_eq!(ts_type(&ty).unwrap(), expected);
+    }
+  }
+
+  #[test]
+  fn test_ts_type_wide_int() {
+    let cases: [syn::Type; 4] = [
+      parse_quote!(u64),
+      parse_quote!(Option<i128>),
+      parse_quote!(Vec<usize>),
+      parse_quote!(HashMap<String, (u8, isize)>),
+    ];
+    for ty in cases {
+      assert!(matches!(ts_type(&ty), Err(crate::util::Error::JsonInt)));
+    }
+  }
+}
@@ -1,3 +1,11 @@
+use syn::Attribute;
+use syn::Expr;
+use syn::ExprLit;
+use syn::Lit;
+use syn::LitStr;
+use syn::Meta;
+use syn::MetaNameValue;
+
 #[derive(Debug)]
 pub enum Error {
   Asyncness,
@@ -5,18 +13,37 @@ pub enum Error {
   UnsupportedType,
   Generics,
   WhereClause,
-  MissingReceiver,
+  Repr,
+  ReprC,
+  Attribute,
+  Property,
+  JsonInt,
 }
 
 impl std::fmt::Display for Error {
   fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
     match self {
-      Error::Asyncness => write!(f, "async functions are not supported"),
+      Error::Asyncness => write!(f, "async methods are not supported"),
       Error::Reciever => write!(f, "methods are not supported"),
       Error::UnsupportedType => write!(f, "unsupported type"),
       Error::Generics => write!(f, "generics are not supported"),
       Error::WhereClause => write!(f, "where clauses are not supported"),
-      Error::MissingReceiver => write!(f, "missing receiver"),
+      Error::Attribute => write!(f, "unsupported attribute"),
+      Error::Property => write!(
+        f,
+        "#[deno_bindgen(property)] is only supported on fieldless enum fields"
+      ),
+      Error::JsonInt => write!(
+        f,
+        "integers wider than 32 bits lose precision as JSON numbers, use f64 or String in serde structs and data enums"
+      ),
+      Error::Repr => {
+        write!(f, "fieldless enums must be #[repr(u8/u16/u32/i8/i16/i32)]")
+      }
+      Error::ReprC => write!(
+        f,
+        "repr_c structs must be #[repr(C)] with named fields of numbers or bools"
+      ),
     }
   }
 }
@@ -24,3 +51,57 @@ impl std::fmt::Display for Error {
 impl std::error::Error for Error {}
 
 pub type Result<T> = std::result::Result<T, Error>;
+
+/// The `///` comments of an item, one line per attribute.
+pub(crate) fn docs(attrs: &[Attribute]) -> String {
+  let lines = attrs
+    .iter()
+    .filter(|attr| attr.path().is_ident("doc"))
+    .filter_map(|attr| match attr.meta {
+      Meta::NameValue(MetaNameValue {
+        value:
+          Expr::Lit(ExprLit {
+            lit: Lit::Str(ref doc),
+            ..
+          }),
+        ..
+      }) => Some(doc.value()),
+      _ => None,
+    })
+    .collect::<Vec<_>>();
+  let lines = lines
+    .iter()
+    .map(|line| line.strip_prefix(' ').unwrap_or(line).trim_end())
+    .collect::<Vec<_>>();
+  lines.join("\n").trim_matches('\n').to_string()
+}
+
+/// The note of a `#[deprecated]` item, empty when there is none.
+pub(crate) fn deprecated(attrs: &[Attribute]) -> Option<String> {
+  let attr = attrs
+    .iter()
+    .find(|attr| attr.path().is_ident("deprecated"))?;
+  let mut note = String::new();
+  match attr.meta {
+    Meta::NameValue(MetaNameValue {
+      value:
+        Expr::Lit(ExprLit {
+          lit: Lit::Str(ref value),
+          ..
+        }),
+      ..
+    }) => note = value.value(),
+    Meta::List(_) => {
+      // Anything we fail to parse is left for rustc to report.
+      let _ = attr.parse_nested_meta(|meta| {
+        let value = meta.value()?.parse::<LitStr>()?;
+        if meta.path.is_ident("note") {
+          note = value.value();
+        }
+        Ok(())
+      });
+    }
+    _ => {}
+  }
+  Some(note)
+}
@@ -2,18 +2,40 @@ const _: () = {
     #[deno_bindgen::linkme::distributed_slice(deno_bindgen::INVENTORY)]
     pub static _A: deno_bindgen::Inventory = deno_bindgen::Inventory::Symbol(deno_bindgen::Symbol {
         name: stringify!(add),
-        parameters: &[deno_bindgen::Type::Int32, deno_bindgen::Type::Int32],
+        parameters: &[
+            deno_bindgen::Parameter {
+                name: "a",
+                ty: deno_bindgen::Type::Int32,
+            },
+            deno_bindgen::Parameter {
+                name: "b",
+                ty: deno_bindgen::Type::Int32,
+            },
+        ],
         return_type: deno_bindgen::Type::Int32,
         non_blocking: false,
         internal: false,
         is_constructor: false,
+        is_static: false,
+        consumes_self: false,
+        int64: deno_bindgen::Int64Mode::Auto,
+        docs: "",
+        deprecated: None,
+        rename: None,
     });
 };
 #[no_mangle]
-extern "C" fn add(a: i32, b: i32) -> i32 {
+extern "C" fn add(a: i32, b: i32, __error: *mut *mut deno_bindgen::OwnedBuffer) -> i32 {
     fn add(a: i32, b: i32) -> i32 {
         a + b
     }
-    let ret = add(a, b);
-    ret
+    unsafe {
+        deno_bindgen::catch_unwind(
+            __error,
+            move || {
+                let ret = add(a, b);
+                ret
+            },
+        )
+    }
 }
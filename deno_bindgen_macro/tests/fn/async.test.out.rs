@@ -0,0 +1,69 @@
+const _: () = {
+    #[deno_bindgen::linkme::distributed_slice(deno_bindgen::INVENTORY)]
+    pub static _A: deno_bindgen::Inventory = deno_bindgen::Inventory::Symbol(deno_bindgen::Symbol {
+        name: stringify!(fetch),
+        parameters: &[
+            deno_bindgen::Parameter {
+                name: "id",
+                ty: deno_bindgen::Type::Uint32,
+            },
+            deno_bindgen::Parameter {
+                name: "key",
+                ty: deno_bindgen::Type::Str,
+            },
+        ],
+        return_type: deno_bindgen::Type::Result(&deno_bindgen::Type::String),
+        non_blocking: true,
+        internal: false,
+        is_constructor: false,
+        is_static: false,
+        consumes_self: false,
+        int64: deno_bindgen::Int64Mode::Auto,
+        docs: "",
+        deprecated: None,
+        rename: None,
+    });
+};
+#[no_mangle]
+extern "C" fn fetch(
+    id: u32,
+    __arg_1: *const (),
+    __arg_2: usize,
+    __error: *mut *mut deno_bindgen::OwnedBuffer,
+) -> *const () {
+    async fn fetch(id: u32, key: &str) -> Result<String, String> {
+        lookup(id, key).await
+    }
+    unsafe {
+        deno_bindgen::catch_unwind(
+            __error,
+            move || {
+                let key = if __arg_2 == 0 {
+                    ""
+                } else {
+                    unsafe {
+                        std::str::from_utf8_unchecked(
+                            std::slice::from_raw_parts(__arg_1 as _, __arg_2),
+                        )
+                    }
+                };
+                let ret = deno_bindgen::__block_on!(fetch(id, key));
+                let ret: *const () = match ret {
+                    Ok(ret) => {
+                        let ret = deno_bindgen::OwnedBuffer::into_raw(ret.into_bytes())
+                            as *mut _;
+                        ret
+                    }
+                    Err(err) => {
+                        let message = err.to_string().into_bytes();
+                        unsafe {
+                            *__error = deno_bindgen::OwnedBuffer::into_raw(message);
+                            std::mem::zeroed()
+                        }
+                    }
+                };
+                ret
+            },
+        )
+    }
+}
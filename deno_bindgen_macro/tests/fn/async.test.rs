@@ -0,0 +1,3 @@
+async fn fetch(id: u32, key: &str) -> Result<String, String> {
+  lookup(id, key).await
+}
@@ -0,0 +1,48 @@
+const _: () = {
+    #[deno_bindgen::linkme::distributed_slice(deno_bindgen::INVENTORY)]
+    pub static _A: deno_bindgen::Inventory = deno_bindgen::Inventory::Symbol(deno_bindgen::Symbol {
+        name: stringify!(either),
+        parameters: &[
+            deno_bindgen::Parameter {
+                name: "a",
+                ty: deno_bindgen::Type::Bool,
+            },
+            deno_bindgen::Parameter {
+                name: "b",
+                ty: deno_bindgen::Type::Optional(&deno_bindgen::Type::Bool),
+            },
+        ],
+        return_type: deno_bindgen::Type::Optional(&deno_bindgen::Type::Bool),
+        non_blocking: false,
+        internal: false,
+        is_constructor: false,
+        is_static: false,
+        consumes_self: false,
+        int64: deno_bindgen::Int64Mode::Auto,
+        docs: "",
+        deprecated: None,
+        rename: None,
+    });
+};
+#[no_mangle]
+extern "C" fn either(
+    a: bool,
+    __arg_1: u8,
+    __arg_2: bool,
+    __error: *mut *mut deno_bindgen::OwnedBuffer,
+) -> deno_bindgen::Optional<bool> {
+    fn either(a: bool, b: Option<bool>) -> Option<bool> {
+        b.map(|b| a || b)
+    }
+    unsafe {
+        deno_bindgen::catch_unwind(
+            __error,
+            move || {
+                let b = if __arg_1 == 0 { None } else { Some({ __arg_2 }) };
+                let ret = either(a, b);
+                let ret = deno_bindgen::Optional::from(ret);
+                ret
+            },
+        )
+    }
+}
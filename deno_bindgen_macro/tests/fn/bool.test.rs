@@ -0,0 +1,3 @@
+fn either(a: bool, b: Option<bool>) -> Option<bool> {
+    b.map(|b| a || b)
+}
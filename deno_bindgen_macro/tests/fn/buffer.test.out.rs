@@ -2,15 +2,30 @@ const _: () = {
     #[deno_bindgen::linkme::distributed_slice(deno_bindgen::INVENTORY)]
     pub static _A: deno_bindgen::Inventory = deno_bindgen::Inventory::Symbol(deno_bindgen::Symbol {
         name: stringify!(write_hello),
-        parameters: &[deno_bindgen::Type::Buffer],
+        parameters: &[
+            deno_bindgen::Parameter {
+                name: "buf",
+                ty: deno_bindgen::Type::Buffer(&deno_bindgen::Type::Uint8),
+            },
+        ],
         return_type: deno_bindgen::Type::Void,
         non_blocking: false,
         internal: false,
         is_constructor: false,
+        is_static: false,
+        consumes_self: false,
+        int64: deno_bindgen::Int64Mode::Auto,
+        docs: "",
+        deprecated: None,
+        rename: None,
     });
 };
 #[no_mangle]
-extern "C" fn write_hello(__arg_0: *const (), __arg_1: u32) {
+extern "C" fn write_hello(
+    __arg_0: *const (),
+    __arg_1: usize,
+    __error: *mut *mut deno_bindgen::OwnedBuffer,
+) {
     fn write_hello(buf: &mut [u8]) {
         buf[0] = b'H';
         buf[1] = b'e';
@@ -18,7 +33,16 @@ extern "C" fn write_hello(__arg_0: *const (), __arg_1: u32) {
         buf[3] = b'l';
         buf[4] = b'o';
     }
-    let buf = unsafe { std::slice::from_raw_parts_mut(__arg_0 as _, __arg_1 as usize) };
-    let ret = write_hello(buf);
-    ret
+    unsafe {
+        deno_bindgen::catch_unwind(
+            __error,
+            move || {
+                let buf = unsafe {
+                    deno_bindgen::FromBuffer::from_buffer(__arg_0, __arg_1)
+                };
+                let ret = write_hello(buf);
+                ret
+            },
+        )
+    }
 }
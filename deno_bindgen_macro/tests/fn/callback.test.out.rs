@@ -0,0 +1,65 @@
+const _: () = {
+    #[deno_bindgen::linkme::distributed_slice(deno_bindgen::INVENTORY)]
+    pub static _A: deno_bindgen::Inventory = deno_bindgen::Inventory::Symbol(deno_bindgen::Symbol {
+        name: stringify!(apply),
+        parameters: &[
+            deno_bindgen::Parameter {
+                name: "f",
+                ty: deno_bindgen::Type::Function(
+                    &deno_bindgen::Callback {
+                        parameters: &[
+                            deno_bindgen::Type::Int32,
+                            deno_bindgen::Type::Int32,
+                        ],
+                        result: deno_bindgen::Type::Int32,
+                        closure: true,
+                        stored: false,
+                    },
+                ),
+            },
+            deno_bindgen::Parameter {
+                name: "g",
+                ty: deno_bindgen::Type::Function(
+                    &deno_bindgen::Callback {
+                        parameters: &[deno_bindgen::Type::Uint8],
+                        result: deno_bindgen::Type::Void,
+                        closure: false,
+                        stored: false,
+                    },
+                ),
+            },
+        ],
+        return_type: deno_bindgen::Type::Int32,
+        non_blocking: false,
+        internal: false,
+        is_constructor: false,
+        is_static: false,
+        consumes_self: false,
+        int64: deno_bindgen::Int64Mode::Auto,
+        docs: "",
+        deprecated: None,
+        rename: None,
+    });
+};
+#[no_mangle]
+extern "C" fn apply(
+    __arg_0: extern "C" fn(i32, i32) -> i32,
+    __arg_1: extern "C" fn(u8),
+    __error: *mut *mut deno_bindgen::OwnedBuffer,
+) -> i32 {
+    fn apply(f: impl Fn(i32, i32) -> i32, g: extern "C" fn(u8)) -> i32 {
+        g(0);
+        f(1, 2)
+    }
+    unsafe {
+        deno_bindgen::catch_unwind(
+            __error,
+            move || {
+                let f = move |__0: i32, __1: i32| __arg_0(__0, __1);
+                let g = __arg_1;
+                let ret = apply(f, g);
+                ret
+            },
+        )
+    }
+}
@@ -0,0 +1,4 @@
+fn apply(f: impl Fn(i32, i32) -> i32, g: extern "C" fn(u8)) -> i32 {
+    g(0);
+    f(1, 2)
+}
@@ -0,0 +1,53 @@
+const _: () = {
+    #[deno_bindgen::linkme::distributed_slice(deno_bindgen::INVENTORY)]
+    pub static _A: deno_bindgen::Inventory = deno_bindgen::Inventory::Symbol(deno_bindgen::Symbol {
+        name: stringify!(scale),
+        parameters: &[
+            deno_bindgen::Parameter {
+                name: "value",
+                ty: deno_bindgen::Type::Int32,
+            },
+            deno_bindgen::Parameter {
+                name: "factor",
+                ty: deno_bindgen::Type::Int32,
+            },
+        ],
+        return_type: deno_bindgen::Type::Int32,
+        non_blocking: false,
+        internal: false,
+        is_constructor: false,
+        is_static: false,
+        consumes_self: false,
+        int64: deno_bindgen::Int64Mode::Auto,
+        docs: "Scales `value`.\n\nNegative factors flip the sign.",
+        deprecated: Some("use `mul` instead"),
+        rename: None,
+    });
+};
+#[no_mangle]
+/// Scales `value`.
+///
+/// Negative factors flip the sign.
+#[allow(deprecated)]
+extern "C" fn scale(
+    value: i32,
+    factor: i32,
+    __error: *mut *mut deno_bindgen::OwnedBuffer,
+) -> i32 {
+    /// Scales `value`.
+    ///
+    /// Negative factors flip the sign.
+    #[deprecated = "use `mul` instead"]
+    fn scale(value: i32, factor: i32) -> i32 {
+        value * factor
+    }
+    unsafe {
+        deno_bindgen::catch_unwind(
+            __error,
+            move || {
+                let ret = scale(value, factor);
+                ret
+            },
+        )
+    }
+}
@@ -0,0 +1,7 @@
+/// Scales `value`.
+///
+/// Negative factors flip the sign.
+#[deprecated = "use `mul` instead"]
+fn scale(value: i32, factor: i32) -> i32 {
+    value * factor
+}
@@ -0,0 +1,6 @@
+fn next_state(state: State) -> State {
+    match state {
+        State::Idle => State::Running,
+        State::Running => State::Idle,
+    }
+}
@@ -0,0 +1,64 @@
+const _: () = {
+    #[deno_bindgen::linkme::distributed_slice(deno_bindgen::INVENTORY)]
+    pub static _A: deno_bindgen::Inventory = deno_bindgen::Inventory::Symbol(deno_bindgen::Symbol {
+        name: stringify!(find),
+        parameters: &[
+            deno_bindgen::Parameter {
+                name: "haystack",
+                ty: deno_bindgen::Type::Optional(&deno_bindgen::Type::Str),
+            },
+            deno_bindgen::Parameter {
+                name: "needle",
+                ty: deno_bindgen::Type::Uint8,
+            },
+        ],
+        return_type: deno_bindgen::Type::Optional(&deno_bindgen::Type::Uint32),
+        non_blocking: false,
+        internal: false,
+        is_constructor: false,
+        is_static: false,
+        consumes_self: false,
+        int64: deno_bindgen::Int64Mode::Auto,
+        docs: "",
+        deprecated: None,
+        rename: None,
+    });
+};
+#[no_mangle]
+extern "C" fn find(
+    __arg_0: u8,
+    __arg_1: *const (),
+    __arg_2: usize,
+    needle: u8,
+    __error: *mut *mut deno_bindgen::OwnedBuffer,
+) -> deno_bindgen::Optional<u32> {
+    fn find(haystack: Option<&str>, needle: u8) -> Option<u32> {
+        haystack?.bytes().position(|b| b == needle).map(|i| i as u32)
+    }
+    unsafe {
+        deno_bindgen::catch_unwind(
+            __error,
+            move || {
+                let haystack = if __arg_0 == 0 {
+                    None
+                } else {
+                    Some({
+                        let haystack = if __arg_2 == 0 {
+                            ""
+                        } else {
+                            unsafe {
+                                std::str::from_utf8_unchecked(
+                                    std::slice::from_raw_parts(__arg_1 as _, __arg_2),
+                                )
+                            }
+                        };
+                        haystack
+                    })
+                };
+                let ret = find(haystack, needle);
+                let ret = deno_bindgen::Optional::from(ret);
+                ret
+            },
+        )
+    }
+}
@@ -0,0 +1,3 @@
+fn find(haystack: Option<&str>, needle: u8) -> Option<u32> {
+    haystack?.bytes().position(|b| b == needle).map(|i| i as u32)
+}
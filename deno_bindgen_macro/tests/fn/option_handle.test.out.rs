@@ -0,0 +1,62 @@
+const _: () = {
+    #[deno_bindgen::linkme::distributed_slice(deno_bindgen::INVENTORY)]
+    pub static _A: deno_bindgen::Inventory = deno_bindgen::Inventory::Symbol(deno_bindgen::Symbol {
+        name: stringify!(next_state),
+        parameters: &[
+            deno_bindgen::Parameter {
+                name: "state",
+                ty: deno_bindgen::Type::Optional(
+                    &deno_bindgen::Type::CustomType("State"),
+                ),
+            },
+        ],
+        return_type: deno_bindgen::Type::Optional(
+            &deno_bindgen::Type::CustomType("State"),
+        ),
+        non_blocking: false,
+        internal: false,
+        is_constructor: false,
+        is_static: false,
+        consumes_self: false,
+        int64: deno_bindgen::Int64Mode::Auto,
+        docs: "",
+        deprecated: None,
+        rename: None,
+    });
+};
+#[no_mangle]
+extern "C" fn next_state(
+    __arg_0: u8,
+    __arg_1: <State as deno_bindgen::IntoHandle>::Raw,
+    __error: *mut *mut deno_bindgen::OwnedBuffer,
+) -> deno_bindgen::Optional<<State as deno_bindgen::IntoHandle>::Raw> {
+    fn next_state(state: Option<State>) -> Option<State> {
+        state
+            .map(|state| match state {
+                State::Idle => State::Running,
+                State::Running => State::Idle,
+            })
+    }
+    unsafe {
+        deno_bindgen::catch_unwind(
+            __error,
+            move || {
+                let state = if __arg_0 == 0 {
+                    None
+                } else {
+                    Some({
+                        let state = unsafe {
+                            deno_bindgen::FromHandle::from_handle(__arg_1)
+                        };
+                        state
+                    })
+                };
+                let ret = next_state(state);
+                let ret = deno_bindgen::Optional::from(
+                    ret.map(deno_bindgen::IntoHandle::into_handle),
+                );
+                ret
+            },
+        )
+    }
+}
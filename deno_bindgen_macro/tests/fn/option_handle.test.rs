@@ -0,0 +1,6 @@
+fn next_state(state: Option<State>) -> Option<State> {
+    state.map(|state| match state {
+        State::Idle => State::Running,
+        State::Running => State::Idle,
+    })
+}
@@ -0,0 +1,60 @@
+const _: () = {
+    #[deno_bindgen::linkme::distributed_slice(deno_bindgen::INVENTORY)]
+    pub static _A: deno_bindgen::Inventory = deno_bindgen::Inventory::Symbol(deno_bindgen::Symbol {
+        name: stringify!(parse_u32),
+        parameters: &[
+            deno_bindgen::Parameter {
+                name: "s",
+                ty: deno_bindgen::Type::Str,
+            },
+        ],
+        return_type: deno_bindgen::Type::Result(&deno_bindgen::Type::Uint32),
+        non_blocking: false,
+        internal: false,
+        is_constructor: false,
+        is_static: false,
+        consumes_self: false,
+        int64: deno_bindgen::Int64Mode::Auto,
+        docs: "",
+        deprecated: None,
+        rename: None,
+    });
+};
+#[no_mangle]
+extern "C" fn parse_u32(
+    __arg_0: *const (),
+    __arg_1: usize,
+    __error: *mut *mut deno_bindgen::OwnedBuffer,
+) -> u32 {
+    fn parse_u32(s: &str) -> Result<u32, std::num::ParseIntError> {
+        s.parse()
+    }
+    unsafe {
+        deno_bindgen::catch_unwind(
+            __error,
+            move || {
+                let s = if __arg_1 == 0 {
+                    ""
+                } else {
+                    unsafe {
+                        std::str::from_utf8_unchecked(
+                            std::slice::from_raw_parts(__arg_0 as _, __arg_1),
+                        )
+                    }
+                };
+                let ret = parse_u32(s);
+                let ret: u32 = match ret {
+                    Ok(ret) => ret,
+                    Err(err) => {
+                        let message = err.to_string().into_bytes();
+                        unsafe {
+                            *__error = deno_bindgen::OwnedBuffer::into_raw(message);
+                            std::mem::zeroed()
+                        }
+                    }
+                };
+                ret
+            },
+        )
+    }
+}
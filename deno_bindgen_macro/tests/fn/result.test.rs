@@ -0,0 +1,3 @@
+fn parse_u32(s: &str) -> Result<u32, std::num::ParseIntError> {
+    s.parse()
+}
@@ -0,0 +1,52 @@
+const _: () = {
+    #[deno_bindgen::linkme::distributed_slice(deno_bindgen::INVENTORY)]
+    pub static _A: deno_bindgen::Inventory = deno_bindgen::Inventory::Symbol(deno_bindgen::Symbol {
+        name: stringify!(greet),
+        parameters: &[
+            deno_bindgen::Parameter {
+                name: "name",
+                ty: deno_bindgen::Type::Str,
+            },
+        ],
+        return_type: deno_bindgen::Type::String,
+        non_blocking: false,
+        internal: false,
+        is_constructor: false,
+        is_static: false,
+        consumes_self: false,
+        int64: deno_bindgen::Int64Mode::Auto,
+        docs: "",
+        deprecated: None,
+        rename: None,
+    });
+};
+#[no_mangle]
+extern "C" fn greet(
+    __arg_0: *const (),
+    __arg_1: usize,
+    __error: *mut *mut deno_bindgen::OwnedBuffer,
+) -> *const () {
+    fn greet(name: &str) -> String {
+        format!("Hello, {}!", name)
+    }
+    unsafe {
+        deno_bindgen::catch_unwind(
+            __error,
+            move || {
+                let name = if __arg_1 == 0 {
+                    ""
+                } else {
+                    unsafe {
+                        std::str::from_utf8_unchecked(
+                            std::slice::from_raw_parts(__arg_0 as _, __arg_1),
+                        )
+                    }
+                };
+                let ret = greet(name);
+                let ret = deno_bindgen::OwnedBuffer::into_raw(ret.into_bytes())
+                    as *mut _;
+                ret
+            },
+        )
+    }
+}
@@ -0,0 +1,44 @@
+const _: () = {
+    #[deno_bindgen::linkme::distributed_slice(deno_bindgen::INVENTORY)]
+    pub static _A: deno_bindgen::Inventory = deno_bindgen::Inventory::Symbol(deno_bindgen::Symbol {
+        name: stringify!(sum),
+        parameters: &[
+            deno_bindgen::Parameter {
+                name: "values",
+                ty: deno_bindgen::Type::Buffer(&deno_bindgen::Type::Float64),
+            },
+        ],
+        return_type: deno_bindgen::Type::Float64,
+        non_blocking: false,
+        internal: false,
+        is_constructor: false,
+        is_static: false,
+        consumes_self: false,
+        int64: deno_bindgen::Int64Mode::Auto,
+        docs: "",
+        deprecated: None,
+        rename: None,
+    });
+};
+#[no_mangle]
+extern "C" fn sum(
+    __arg_0: *const (),
+    __arg_1: usize,
+    __error: *mut *mut deno_bindgen::OwnedBuffer,
+) -> f64 {
+    fn sum(values: &[f64]) -> f64 {
+        values.iter().sum()
+    }
+    unsafe {
+        deno_bindgen::catch_unwind(
+            __error,
+            move || {
+                let values = unsafe {
+                    deno_bindgen::FromBuffer::from_buffer(__arg_0, __arg_1)
+                };
+                let ret = sum(values);
+                ret
+            },
+        )
+    }
+}
@@ -0,0 +1,3 @@
+fn sum(values: &[f64]) -> f64 {
+    values.iter().sum()
+}
@@ -0,0 +1,46 @@
+const _: () = {
+    #[deno_bindgen::linkme::distributed_slice(deno_bindgen::INVENTORY)]
+    pub static _A: deno_bindgen::Inventory = deno_bindgen::Inventory::Symbol(deno_bindgen::Symbol {
+        name: stringify!(offset),
+        parameters: &[
+            deno_bindgen::Parameter {
+                name: "base",
+                ty: deno_bindgen::Type::Usize,
+            },
+            deno_bindgen::Parameter {
+                name: "delta",
+                ty: deno_bindgen::Type::Isize,
+            },
+        ],
+        return_type: deno_bindgen::Type::Optional(&deno_bindgen::Type::Usize),
+        non_blocking: false,
+        internal: false,
+        is_constructor: false,
+        is_static: false,
+        consumes_self: false,
+        int64: deno_bindgen::Int64Mode::Auto,
+        docs: "",
+        deprecated: None,
+        rename: None,
+    });
+};
+#[no_mangle]
+extern "C" fn offset(
+    base: usize,
+    delta: isize,
+    __error: *mut *mut deno_bindgen::OwnedBuffer,
+) -> deno_bindgen::Optional<usize> {
+    fn offset(base: usize, delta: isize) -> Option<usize> {
+        base.checked_add_signed(delta)
+    }
+    unsafe {
+        deno_bindgen::catch_unwind(
+            __error,
+            move || {
+                let ret = offset(base, delta);
+                let ret = deno_bindgen::Optional::from(ret);
+                ret
+            },
+        )
+    }
+}
@@ -0,0 +1,3 @@
+fn offset(base: usize, delta: isize) -> Option<usize> {
+    base.checked_add_signed(delta)
+}
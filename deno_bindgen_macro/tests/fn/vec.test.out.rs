@@ -0,0 +1,50 @@
+const _: () = {
+    #[deno_bindgen::linkme::distributed_slice(deno_bindgen::INVENTORY)]
+    pub static _A: deno_bindgen::Inventory = deno_bindgen::Inventory::Symbol(deno_bindgen::Symbol {
+        name: stringify!(squares),
+        parameters: &[
+            deno_bindgen::Parameter {
+                name: "n",
+                ty: deno_bindgen::Type::Uint32,
+            },
+        ],
+        return_type: deno_bindgen::Type::Optional(
+            &deno_bindgen::Type::Vec(&deno_bindgen::Type::Float64),
+        ),
+        non_blocking: false,
+        internal: false,
+        is_constructor: false,
+        is_static: false,
+        consumes_self: false,
+        int64: deno_bindgen::Int64Mode::Auto,
+        docs: "",
+        deprecated: None,
+        rename: None,
+    });
+};
+#[no_mangle]
+extern "C" fn squares(
+    n: u32,
+    __error: *mut *mut deno_bindgen::OwnedBuffer,
+) -> *const () {
+    fn squares(n: u32) -> Option<Box<[f64]>> {
+        Some((0..n).map(|i| (i * i) as f64).collect())
+    }
+    unsafe {
+        deno_bindgen::catch_unwind(
+            __error,
+            move || {
+                let ret = squares(n);
+                let ret: *const () = match ret {
+                    Some(ret) => {
+                        let ret = deno_bindgen::OwnedBuffer::into_raw(Vec::from(ret))
+                            as *mut _;
+                        ret
+                    }
+                    None => std::ptr::null(),
+                };
+                ret
+            },
+        )
+    }
+}
@@ -0,0 +1,3 @@
+fn squares(n: u32) -> Option<Box<[f64]>> {
+    Some((0..n).map(|i| (i * i) as f64).collect())
+}
@@ -1,19 +1,82 @@
 import {
   add,
   add2,
+  add_async,
   buf_mut,
+  byte_count,
   bytelen,
+  call_twice,
+  checked_add,
+  checked_div,
+  checked_index,
+  checked_pow2,
+  checked_sample,
+  count_if,
+  cross,
   cstr,
+  default_config,
+  describe_config,
+  describe_event,
+  Direction,
+  find_index,
   Foo,
+  Image,
+  greet,
+  greet_non_blocking,
+  half,
   inc_foo,
   Input,
+  is_even,
+  is_north,
+  Level,
+  level_of,
   make_foo,
+  make_foo_checked,
+  maybe_bytelen,
+  maybe_greet,
+  maybe_inc_foo,
+  maybe_turn_right,
+  maybe_make_foo,
+  maybe_sample,
+  max_i64,
+  maybe_not,
+  midpoint,
+  mirror_event,
+  negate_i32,
   non_blocking,
+  normalize,
+  notify,
+  offset,
+  packVec3,
+  parse_async,
+  parse_int,
+  pow2,
+  pow2_bigint,
+  pow2_number,
+  repeat_byte,
+  set_listener,
+  shout,
+  squares,
+  steps,
+  str_len,
   strlen,
+  sum_by,
+  sum_f32,
+  sum_f64,
+  sum_u16,
+  Tally,
+  Toggle,
+  try_greet,
+  turn_right,
+  unpackVec3,
+  validate_non_blocking,
+  xor,
 } from "./bindings/mod.ts";
 import {
   assert,
   assertEquals,
+  assertRejects,
+  assertThrows,
 } from "https://deno.land/std@0.178.0/testing/asserts.ts";
 
 Deno.test({
@@ -42,6 +105,34 @@ Deno.test({
   },
 });
 
+Deno.test({
+  name: "typed_array#test",
+  fn: () => {
+    assertEquals(sum_f32(new Float32Array([0.5, 1.5, 2])), 4);
+    assertEquals(sum_f64(new Float64Array([0.25, 0.5])), 0.75);
+    assertEquals(sum_f64(new Float64Array()), 0);
+    assertEquals(sum_u16(new Uint16Array([65535, 1])), 65536);
+    assertEquals(max_i64(new BigInt64Array([-5n, 3n, 2n])), 3);
+
+    const values = new Int32Array([1, -2, 3]);
+    negate_i32(values);
+    assertEquals(Array.from(values), [-1, 2, -3]);
+
+    // Views into a buffer keep the element alignment.
+    const view = new Int32Array(new ArrayBuffer(16), 4, 2);
+    view.set([7, 8]);
+    negate_i32(view);
+    assertEquals(Array.from(view), [-7, -8]);
+
+    // The element count is derived from the byte length on the Rust side.
+    assertThrows(
+      () => sum_f32(new Uint8Array(6) as unknown as Float32Array),
+      Error,
+      "buffer of 6 bytes is not a multiple of 4 bytes",
+    );
+  },
+});
+
 Deno.test({
   name: "cstr#test",
   fn: () => {
@@ -67,6 +158,158 @@ Deno.test({
   },
 });
 
+Deno.test({
+  name: "str_len#test",
+  fn: () => {
+    assertEquals(str_len(""), 0);
+    assertEquals(str_len("héllo 🦕"), 7);
+  },
+});
+
+Deno.test({
+  name: "greet#test",
+  fn: () => {
+    assertEquals(greet("Deno"), "Hello, Deno!");
+    assertEquals(greet(""), "Hello, !");
+  },
+});
+
+Deno.test({
+  name: "greet_non_blocking#test",
+  fn: async () => {
+    assertEquals(await greet_non_blocking("🦕"), "Hello, 🦕!");
+  },
+});
+
+Deno.test({
+  name: "parse_int#test",
+  fn: () => {
+    assertEquals(parse_int("42"), 42);
+    assertThrows(() => parse_int("foo"), Error, "invalid digit");
+  },
+});
+
+Deno.test({
+  name: "checked_div#test",
+  fn: () => {
+    assertEquals(checked_div(6, 3), 2);
+    assertThrows(() => checked_div(1, 0), Error, "division by zero");
+    // The error slot is reset after a failure.
+    assertEquals(checked_div(4, 2), 2);
+  },
+});
+
+Deno.test({
+  name: "try_greet#test",
+  fn: () => {
+    assertEquals(try_greet("Deno"), "Hello, Deno!");
+    assertThrows(() => try_greet(""), Error, "name must not be empty");
+  },
+});
+
+Deno.test({
+  name: "validate_non_blocking#test",
+  fn: async () => {
+    await validate_non_blocking(1);
+    await assertRejects(
+      () => validate_non_blocking(-1),
+      Error,
+      "-1 is negative",
+    );
+  },
+});
+
+Deno.test({
+  name: "panic#test",
+  fn: () => {
+    assertEquals(checked_index(new Uint8Array([1, 2]), 1), 2);
+    assertThrows(
+      () => checked_index(new Uint8Array([1, 2]), 2),
+      Error,
+      "index out of bounds",
+    );
+    // The library is still usable afterwards.
+    assertEquals(checked_index(new Uint8Array([3]), 0), 3);
+  },
+});
+
+Deno.test({
+  name: "async#test",
+  fn: async () => {
+    assertEquals(await add_async(1, 2), 3);
+    assertEquals(await parse_async("42"), 42);
+    await assertRejects(
+      () => parse_async("forty-two"),
+      Error,
+      "invalid digit found in string",
+    );
+  },
+});
+
+Deno.test({
+  name: "make_foo_checked#test",
+  fn: () => {
+    using foo = make_foo_checked(1);
+    assert(foo instanceof Foo);
+    assertThrows(() => make_foo_checked(0), Error, "non-zero");
+  },
+});
+
+Deno.test({
+  name: "checked_add#test",
+  fn: () => {
+    assertEquals(checked_add(1, 2), 3);
+    assertEquals(checked_add(-1, null), null);
+    assertEquals(checked_add(2147483647, 1), null);
+  },
+});
+
+Deno.test({
+  name: "half#test",
+  fn: () => {
+    assertEquals(half(3), 1.5);
+    assertEquals(half(NaN), null);
+  },
+});
+
+Deno.test({
+  name: "maybe_greet#test",
+  fn: () => {
+    assertEquals(maybe_greet("Deno"), "Hello, Deno!");
+    assertEquals(maybe_greet(""), "Hello, !");
+    assertEquals(maybe_greet(null), null);
+  },
+});
+
+Deno.test({
+  name: "maybe_bytelen#test",
+  fn: () => {
+    assertEquals(maybe_bytelen(new Uint8Array(3)), 3);
+    assertEquals(maybe_bytelen(new Uint8Array(0)), 0);
+    assertEquals(maybe_bytelen(null), 0xffffffff);
+  },
+});
+
+Deno.test({
+  name: "maybe_make_foo#test",
+  fn: () => {
+    using foo = maybe_make_foo(1)!;
+    assert(foo instanceof Foo);
+    assertEquals(foo.bar(1), 2);
+    assertEquals(maybe_make_foo(null), null);
+  },
+});
+
+Deno.test({
+  name: "maybe_inc_foo#test",
+  fn: () => {
+    using foo = new Foo(1);
+    assertEquals(maybe_inc_foo(foo), 1);
+    assertEquals(foo.bar(0), 2);
+    assertEquals(maybe_inc_foo(null), 0);
+  },
+});
+
 Deno.test({
   name: "make_foo#test",
   fn: () => {
@@ -101,6 +344,235 @@ Deno.test({
     // Multiple dipose calls are nop.
     foo[Symbol.dispose]();
     foo[Symbol.dispose]();
+
+    // Disposed objects can't be passed to Rust.
+    assertThrows(() => inc_foo(foo), Error, "object has been consumed");
+    assertThrows(() => maybe_inc_foo(foo), Error, "object has been consumed");
+  },
+});
+
+Deno.test({
+  name: "Tally#properties",
+  fn() {
+    using counter = new Tally("clicks", 2);
+    counter.tick();
+    assertEquals(counter.count, 2);
+    assertEquals(counter.label, "clicks");
+    assertEquals(counter.direction, Direction.North);
+
+    counter.count = 10;
+    counter.label = "taps";
+    counter.direction = Direction.West;
+    counter.tick();
+    assertEquals(counter.count, 12);
+    assertEquals(counter.label, "taps");
+    assertEquals(counter.direction, Direction.West);
+    assert(!("step" in counter));
+
+    counter.tickBy(3);
+    assertEquals(counter.count, 18);
+  },
+});
+
+Deno.test({
+  name: "Tally#static",
+  fn() {
+    assertEquals(Tally.default_step(), 1);
+
+    using counter = Tally.starting_at("laps", 5);
+    assert(counter instanceof Tally);
+    counter.tick();
+    assertEquals(counter.count, 6);
+    assertEquals(counter.label, "laps");
+  },
+});
+
+Deno.test({
+  name: "Tally#fork",
+  fn() {
+    using counter = new Tally("clicks", 2);
+    counter.tick();
+
+    using fork = counter.fork("copy")!;
+    assert(fork instanceof Tally);
+    assertEquals(fork.label, "copy");
+    assertEquals(fork.count, 2);
+
+    fork.tick();
+    assertEquals(fork.count, 4);
+    assertEquals(counter.count, 2);
+
+    assertEquals(counter.fork(""), null);
+  },
+});
+
+Deno.test({
+  name: "Tally#consume",
+  fn() {
+    using counter = new Tally("laps", 3);
+    counter.tick();
+
+    using reset = counter.reset();
+    assertEquals(counter.ptr, null);
+    assertEquals(reset.count, 0);
+    assertThrows(() => counter.tick(), Error, "object has been consumed");
+    assertThrows(() => counter.count, Error, "object has been consumed");
+
+    reset.tick();
+    assertEquals(reset.finish(), "laps: 3");
+    assertThrows(() => reset.finish(), Error, "object has been consumed");
+
+    // Disposing a consumed object is a no-op.
+    reset[Symbol.dispose]();
+  },
+});
+
+Deno.test({
+  name: "Tally#finalize",
+  fn() {
+    const counter = new Tally("laps", 1);
+    // Registered on creation, `unregister` reports whether it was.
+    assert(Tally.__registry.unregister(counter));
+    Tally.__registry.register(counter, counter.ptr, counter);
+
+    counter[Symbol.dispose]();
+    assertEquals(Tally.__registry.unregister(counter), false);
+
+    using forked = new Tally("laps", 1).fork("copy")!;
+    using reset = forked.reset();
+    assertEquals(Tally.__registry.unregister(forked), false);
+    assert(Tally.__registry.unregister(reset));
+    Tally.__registry.register(reset, reset.ptr, reset);
+  },
+});
+
+Deno.test({
+  name: "int64#test",
+  fn: () => {
+    assertEquals(pow2(10), 1024);
+    assertEquals(pow2(63), 2n ** 63n);
+
+    assertEquals(pow2_bigint(10), 1024n);
+    assertEquals(pow2_bigint(63), 2n ** 63n);
+
+    assertEquals(pow2_number(10), 1024);
+    assertThrows(() => pow2_number(63), RangeError, "not a safe integer");
+
+    assertEquals(checked_pow2(10), 1024);
+    assertEquals(checked_pow2(63), 2n ** 63n);
+    assertEquals(checked_pow2(64), null);
+  },
+});
+
+Deno.test({
+  name: "usize#test",
+  fn: () => {
+    assertEquals(byte_count(new Uint8Array(0)), 0);
+    assertEquals(byte_count(new Uint8Array(300)), 300);
+
+    assertEquals(offset(10, -3), 7);
+    assertEquals(offset(2n ** 63n, 1), 2n ** 63n + 1n);
+    assertEquals(offset(0, -1), null);
+  },
+});
+
+Deno.test({
+  name: "vec#test",
+  fn: () => {
+    assertEquals(repeat_byte(7, 3), new Uint8Array([7, 7, 7]));
+    assertEquals(repeat_byte(7, 0), new Uint8Array(0));
+
+    assertEquals(steps(1, 0.5, 3), new Float64Array([1, 1.5, 2]));
+
+    assertEquals(squares(4), new BigUint64Array([0n, 1n, 4n, 9n]));
+    assertEquals(squares(-1), null);
+  },
+});
+
+Deno.test({
+  name: "Image#test",
+  fn: () => {
+    using image = new Image(4, 2);
+    const pixels = image.pixels();
+    assertEquals(pixels, new Uint8Array(8));
+
+    // Both views share the memory owned by the image.
+    image.pixels_mut().set([255, 128, 64, 0], 4);
+    assertEquals(pixels, new Uint8Array([0, 0, 0, 0, 255, 128, 64, 0]));
+
+    assertEquals(image.histogram(), new Uint32Array([5, 1, 1, 1]));
+    assertEquals(new Image(0, 0).pixels(), new Uint8Array(0));
+  },
+});
+
+Deno.test({
+  name: "repr_c#test",
+  fn: () => {
+    assertEquals(cross({ x: 1, y: 0, z: 0 }, { x: 0, y: 1, z: 0 }), {
+      x: 0,
+      y: 0,
+      z: 1,
+    });
+
+    assertEquals(checked_sample(2n ** 63n, -1.5), {
+      valid: false,
+      id: 2n ** 63n,
+      value: -1.5,
+    });
+    assertThrows(() => checked_sample(3n, NaN), Error, "sample 3 is NaN");
+
+    assertEquals(normalize({ x: 0, y: 3, z: 0 }), { x: 0, y: 1, z: 0 });
+    assertEquals(normalize({ x: 0, y: 0, z: 0 }), null);
+    assertEquals(normalize(null), null);
+    assertEquals(maybe_sample(7n, 0.5), { valid: true, id: 7n, value: 0.5 });
+    assertEquals(maybe_sample(7n, null), null);
+
+    const packed = packVec3({ x: 1, y: 2, z: 3 });
+    assertEquals(packed, new Uint8Array(new Float32Array([1, 2, 3]).buffer));
+    assertEquals(unpackVec3(packed), { x: 1, y: 2, z: 3 });
+  },
+});
+
+Deno.test({
+  name: "bool#test",
+  fn: async () => {
+    assertEquals(xor(true, false), true);
+    assertEquals(xor(true, true), false);
+    assertEquals(await is_even(4), true);
+    assertEquals(await is_even(3), false);
+
+    assertEquals(maybe_not(true), false);
+    assertEquals(maybe_not(false), true);
+    assertEquals(maybe_not(null), null);
+
+    assertEquals(count_if((i) => i % 3 === 0, 10), 4);
+
+    using toggle = new Toggle(false);
+    assertEquals(toggle.on, false);
+    assertEquals(toggle.flip(), true);
+    toggle.on = false;
+    assertEquals(toggle.flip(), true);
+  },
+});
+
+Deno.test({
+  name: "rename#test",
+  fn: () => {
+    assertEquals(shout("deno"), "HELLO, DENO!");
+  },
+});
+
+Deno.test({
+  name: "safe-handles#test",
+  // Only checked when built with the `safe-handles` feature.
+  ignore: Deno.env.get("SAFE_HANDLES") !== "1",
+  fn: () => {
+    const foo = new Foo(1);
+    const stale = Foo.__constructor(foo.ptr);
+    foo[Symbol.dispose]();
+
+    assertThrows(() => stale.bar(0), Error, "invalid Foo handle");
+    assertThrows(() => inc_foo(stale), Error, "invalid Foo handle");
   },
 });
 
@@ -112,3 +584,135 @@ Deno.test({
     assertEquals(foo.bar(0), 23);
   },
 });
+
+Deno.test({
+  name: "Foo#describe",
+  fn: () => {
+    using foo = new Foo(7);
+    assertEquals(foo.describe("foo: "), "foo: 7");
+  },
+});
+
+Deno.test({
+  name: "Foo#checked_sub",
+  fn: () => {
+    using foo = new Foo(1);
+    assertEquals(foo.checked_sub(1), 0);
+    assertThrows(() => foo.checked_sub(2), Error, "1 - 2 overflows");
+  },
+});
+
+Deno.test({
+  name: "serde#test",
+  fn: () => {
+    assertEquals(
+      describe_config({
+        name: "deno",
+        maxRetries: 5,
+        tags: ["a", "b"],
+        origin: null,
+      }),
+      "deno (5 retries, 2 tags)",
+    );
+
+    assertEquals(default_config("ffi"), {
+      name: "ffi",
+      maxRetries: 3,
+      tags: ["default"],
+      origin: { x: 0.5, y: -1 },
+    });
+
+    assertEquals(midpoint({ x: 0, y: 0 }, { x: 2, y: 4 }), { x: 1, y: 2 });
+    assertEquals(midpoint({ x: 0, y: 0 }, null), null);
+  },
+});
+
+Deno.test({
+  name: "enum#test",
+  fn: () => {
+    assertEquals(turn_right(Direction.North), Direction.East);
+    assertEquals(turn_right(Direction.East), Direction.South);
+    assertEquals(turn_right(Direction.West), Direction.North);
+    assertEquals(Direction.South, 10);
+    assertEquals(Direction.West, 11);
+    assertEquals(is_north(Direction.North), true);
+    assertEquals(is_north(Direction.East), false);
+    assertEquals(maybe_turn_right(Direction.South), Direction.West);
+    assertEquals(maybe_turn_right(null), null);
+
+    // Discriminants are validated on the Rust side.
+    assertThrows(
+      () => turn_right(2 as Direction),
+      Error,
+      "invalid discriminant 2 for Direction",
+    );
+    assertThrows(
+      () => maybe_turn_right(12 as Direction),
+      Error,
+      "invalid discriminant 12 for Direction",
+    );
+
+    assertEquals(level_of(-5), Level.Low);
+    assertEquals(level_of(5), Level.High);
+  },
+});
+
+Deno.test({
+  name: "data_enum#test",
+  fn: () => {
+    assertEquals(
+      describe_event({ type: "Click", x: 1, y: 2 }),
+      "click at 1, 2",
+    );
+    assertEquals(describe_event({ type: "Key", 0: 13 }), "key 13");
+    assertEquals(
+      describe_event({ type: "Text", 0: "a", 1: true }),
+      "text a true",
+    );
+    assertEquals(describe_event({ type: "Quit" }), "quit");
+
+    assertEquals(mirror_event({ type: "Click", x: 1, y: -2 }), {
+      type: "Click",
+      x: -1,
+      y: 2,
+    });
+    assertEquals(mirror_event({ type: "Key", 0: 7 }), { type: "Key", 0: 7 });
+  },
+});
+
+Deno.test({
+  name: "callback#test",
+  fn: () => {
+    assertEquals(call_twice((x) => x * 3, 2), 18);
+    assertEquals(sum_by((i) => i / 2, 4), 3);
+
+    // Stored callbacks are owned by the caller.
+    assertThrows(
+      () => set_listener((() => {}) as unknown as Deno.UnsafeCallback),
+      TypeError,
+      "pass a Deno.UnsafeCallback",
+    );
+    const received: number[] = [];
+    const listener = new Deno.UnsafeCallback(
+      { parameters: ["u32"], result: "void" },
+      (value) => {
+        received.push(value);
+      },
+    );
+    set_listener(listener);
+    notify(1);
+    notify(2);
+    assertEquals(received, [1, 2]);
+    listener.close();
+  },
+});
+
+Deno.test({
+  name: "callback_error#test",
+  fn: async () => {
+    assertEquals(await find_index((i) => i == 3, 5), 3);
+    // The thread-safe callback is closed even though the call rejects,
+    // otherwise it keeps the event loop alive.
+    await assertRejects(() => find_index(() => false, 2), Error, "no match");
+  },
+});
@@ -1,5 +1,8 @@
+use std::sync::Mutex;
+
 use deno_bindgen::deno_bindgen;
 
+/// Adds two numbers.
 #[deno_bindgen]
 fn add(a: i32, b: i32) -> i32 {
   a + b
@@ -34,6 +37,210 @@ fn buf_mut(b: &mut [u8]) {
   b[0] = 99;
 }
 
+#[deno_bindgen]
+fn sum_f32(values: &[f32]) -> f32 {
+  values.iter().sum()
+}
+
+#[deno_bindgen]
+fn sum_f64(values: &[f64]) -> f64 {
+  values.iter().sum()
+}
+
+#[deno_bindgen]
+fn sum_u16(values: &[u16]) -> u32 {
+  values.iter().map(|v| *v as u32).sum()
+}
+
+#[deno_bindgen]
+fn negate_i32(values: &mut [i32]) {
+  for v in values {
+    *v = -*v;
+  }
+}
+
+#[deno_bindgen]
+fn max_i64(values: &[i64]) -> i64 {
+  values.iter().copied().max().unwrap_or(0)
+}
+
+#[deno_bindgen]
+fn pow2(exp: u32) -> u64 {
+  1 << exp
+}
+
+#[deno_bindgen(int64 = "bigint")]
+fn pow2_bigint(exp: u32) -> u64 {
+  1 << exp
+}
+
+#[deno_bindgen(int64 = "number")]
+fn pow2_number(exp: u32) -> u64 {
+  1 << exp
+}
+
+#[deno_bindgen]
+fn checked_pow2(exp: u32) -> Option<u64> {
+  1u64.checked_shl(exp)
+}
+
+#[deno_bindgen]
+fn byte_count(b: &[u8]) -> usize {
+  b.len()
+}
+
+#[deno_bindgen]
+fn offset(base: usize, delta: isize) -> Option<usize> {
+  base.checked_add_signed(delta)
+}
+
+#[deno_bindgen]
+fn repeat_byte(byte: u8, times: usize) -> Vec<u8> {
+  vec![byte; times]
+}
+
+#[deno_bindgen]
+fn steps(start: f64, step: f64, len: u32) -> Box<[f64]> {
+  (0..len).map(|i| start + step * i as f64).collect()
+}
+
+#[deno_bindgen]
+fn squares(n: i32) -> Option<Vec<u64>> {
+  if n < 0 {
+    return None;
+  }
+  Some((0..n as u64).map(|i| i * i).collect())
+}
+
+#[deno_bindgen]
+fn xor(a: bool, b: bool) -> bool {
+  a ^ b
+}
+
+#[deno_bindgen(non_blocking)]
+fn is_even(n: i32) -> bool {
+  n % 2 == 0
+}
+
+#[deno_bindgen]
+fn maybe_not(value: Option<bool>) -> Option<bool> {
+  value.map(|value| !value)
+}
+
+#[deno_bindgen]
+fn count_if(pred: impl Fn(u32) -> bool, len: u32) -> u32 {
+  (0..len).filter(|i| pred(*i)).count() as u32
+}
+
+#[deno_bindgen]
+struct Toggle {
+  pub on: bool,
+}
+
+#[deno_bindgen]
+impl Toggle {
+  #[constructor]
+  fn new(on: bool) -> Toggle {
+    Toggle { on }
+  }
+
+  fn flip(&mut self) -> bool {
+    self.on = !self.on;
+    self.on
+  }
+}
+
+#[deno_bindgen(repr_c)]
+pub struct Vec3 {
+  pub x: f32,
+  pub y: f32,
+  pub z: f32,
+}
+
+#[deno_bindgen]
+fn cross(a: Vec3, b: Vec3) -> Vec3 {
+  Vec3 {
+    x: a.y * b.z - a.z * b.y,
+    y: a.z * b.x - a.x * b.z,
+    z: a.x * b.y - a.y * b.x,
+  }
+}
+
+#[deno_bindgen]
+fn normalize(v: Option<Vec3>) -> Option<Vec3> {
+  let v = v?;
+  let len = (v.x * v.x + v.y * v.y + v.z * v.z).sqrt();
+  if len == 0.0 {
+    return None;
+  }
+  Some(Vec3 {
+    x: v.x / len,
+    y: v.y / len,
+    z: v.z / len,
+  })
+}
+
+#[deno_bindgen(repr_c, rename = "Sample")]
+pub struct RawSample {
+  pub valid: bool,
+  pub id: u64,
+  pub value: f64,
+}
+
+#[deno_bindgen]
+fn checked_sample(id: u64, value: f64) -> Result<RawSample, String> {
+  if value.is_nan() {
+    return Err(format!("sample {} is NaN", id));
+  }
+  Ok(RawSample {
+    valid: value >= 0.0,
+    id,
+    value,
+  })
+}
+
+#[deno_bindgen]
+fn maybe_sample(id: u64, value: Option<f64>) -> Option<RawSample> {
+  Some(RawSample {
+    valid: true,
+    id,
+    value: value?,
+  })
+}
+
+#[deno_bindgen]
+struct Image {
+  pixels: Vec<u8>,
+  histogram: [u32; 4],
+}
+
+#[deno_bindgen]
+impl Image {
+  #[constructor]
+  fn new(width: u32, height: u32) -> Image {
+    Image {
+      pixels: vec![0; (width * height) as usize],
+      histogram: [0; 4],
+    }
+  }
+
+  fn pixels(&self) -> &[u8] {
+    &self.pixels
+  }
+
+  fn pixels_mut(&mut self) -> &mut [u8] {
+    &mut self.pixels
+  }
+
+  fn histogram(&mut self) -> &[u32] {
+    self.histogram = [0; 4];
+    for pixel in &self.pixels {
+      self.histogram[(pixel / 64) as usize] += 1;
+    }
+    &self.histogram
+  }
+}
+
 #[deno_bindgen]
 fn cstr() -> *const u8 {
   b"Hello, World!\0".as_ptr()
@@ -55,16 +262,132 @@ fn non_blocking() -> i32 {
   42
 }
 
+#[deno_bindgen]
+fn str_len(s: &str) -> u32 {
+  s.chars().count() as u32
+}
+
+#[deno_bindgen(rename = "shout")]
+fn greet_loudly(name: &str) -> String {
+  format!("HELLO, {}!", name.to_uppercase())
+}
+
+#[deno_bindgen]
+fn greet(name: &str) -> String {
+  format!("Hello, {}!", name)
+}
+
+#[deno_bindgen(non_blocking)]
+fn greet_non_blocking(name: String) -> String {
+  format!("Hello, {}!", name)
+}
+
+#[deno_bindgen]
+fn parse_int(s: &str) -> Result<i32, std::num::ParseIntError> {
+  s.parse()
+}
+
+#[deno_bindgen]
+#[deprecated = "use `checked_div` instead"]
+fn div(a: i32, b: i32) -> i32 {
+  a / b
+}
+
+#[deno_bindgen]
+fn checked_div(a: i32, b: i32) -> Result<i32, String> {
+  a.checked_div(b)
+    .ok_or_else(|| "division by zero".to_string())
+}
+
+#[deno_bindgen]
+fn try_greet(name: &str) -> Result<String, String> {
+  if name.is_empty() {
+    return Err("name must not be empty".to_string());
+  }
+  Ok(format!("Hello, {}!", name))
+}
+
+#[deno_bindgen(non_blocking)]
+fn validate_non_blocking(value: i32) -> Result<(), String> {
+  if value < 0 {
+    return Err(format!("{} is negative", value));
+  }
+  Ok(())
+}
+
+#[deno_bindgen]
+fn checked_index(values: &[u8], index: u32) -> u8 {
+  values[index as usize]
+}
+
+#[deno_bindgen]
+async fn add_async(a: i32, b: i32) -> i32 {
+  std::future::ready(a + b).await
+}
+
+#[deno_bindgen]
+async fn parse_async(s: String) -> Result<i32, std::num::ParseIntError> {
+  std::future::ready(s.parse()).await
+}
+
+#[deno_bindgen]
+fn make_foo_checked(internal: u32) -> Result<Foo, String> {
+  if internal == 0 {
+    return Err("internal must be non-zero".to_string());
+  }
+  Ok(Foo { internal })
+}
+
+#[deno_bindgen]
+fn checked_add(a: Option<i32>, b: Option<i32>) -> Option<i32> {
+  a?.checked_add(b?)
+}
+
+#[deno_bindgen]
+fn half(a: f64) -> Option<f64> {
+  if a.is_nan() {
+    return None;
+  }
+  Some(a / 2.0)
+}
+
+#[deno_bindgen]
+fn maybe_greet(name: Option<&str>) -> Option<String> {
+  name.map(|name| format!("Hello, {}!", name))
+}
+
+#[deno_bindgen]
+fn maybe_bytelen(b: Option<&[u8]>) -> u32 {
+  b.map_or(u32::MAX, |b| b.len() as u32)
+}
+
+#[deno_bindgen]
+fn maybe_make_foo(internal: Option<u32>) -> Option<Foo> {
+  internal.map(|internal| Foo { internal })
+}
+
+#[deno_bindgen]
+fn maybe_inc_foo(target: Option<&mut Foo>) -> u8 {
+  match target {
+    Some(target) => {
+      target.internal += 1;
+      1
+    }
+    None => 0,
+  }
+}
+
 #[deno_bindgen]
 fn make_foo() -> Foo {
   Foo { internal: 42 }
 }
 
 #[deno_bindgen]
-fn inc_foo(foo: &mut Foo) {
-  foo.internal += 1;
+fn inc_foo(target: &mut Foo) {
+  target.internal += 1;
 }
 
+/// A counter behind an opaque handle.
 #[deno_bindgen]
 pub struct Foo {
   internal: u32,
@@ -76,12 +399,238 @@ impl Foo {
   fn new(internal: u32) -> Foo {
     Foo { internal }
   }
-  
+
   fn inc(&mut self) {
     self.internal += 1;
   }
 
+  /// Adds `a` to the counter, without changing it.
+  ///
+  /// Returns the sum.
   fn bar(&self, a: u32) -> u32 {
     self.internal + a
   }
-}
\ No newline at end of file
+
+  fn describe(&self, prefix: &str) -> String {
+    format!("{}{}", prefix, self.internal)
+  }
+
+  fn checked_sub(&self, a: u32) -> Result<u32, String> {
+    self
+      .internal
+      .checked_sub(a)
+      .ok_or_else(|| format!("{} - {} overflows", self.internal, a))
+  }
+}
+#[deno_bindgen(rename = "Tally", finalize)]
+struct Counter {
+  pub label: String,
+  pub count: u32,
+  #[deno_bindgen(property)]
+  pub direction: Direction,
+  #[deno_bindgen(skip)]
+  pub step: u32,
+}
+
+#[deno_bindgen]
+impl Counter {
+  #[constructor]
+  fn new(label: &str, step: u32) -> Counter {
+    Counter {
+      label: label.to_string(),
+      count: 0,
+      direction: Direction::North,
+      step,
+    }
+  }
+
+  fn starting_at(label: &str, count: u32) -> Self {
+    Self {
+      count,
+      ..Counter::new(label, Counter::default_step())
+    }
+  }
+
+  fn default_step() -> u32 {
+    1
+  }
+
+  fn tick(&mut self) {
+    self.count += self.step;
+  }
+
+  fn fork(&self, label: &str) -> Option<Counter> {
+    (!label.is_empty()).then(|| Counter {
+      label: label.to_string(),
+      count: self.count,
+      direction: self.direction,
+      step: self.step,
+    })
+  }
+
+  fn reset(mut self) -> Counter {
+    self.count = 0;
+    self
+  }
+
+  fn finish(self) -> String {
+    format!("{}: {}", self.label, self.count)
+  }
+
+  #[deno_bindgen(rename = "tickBy")]
+  fn tick_by(&mut self, times: u32) {
+    self.count += self.step * times;
+  }
+}
+
+#[deno_bindgen(serde)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Config {
+  name: String,
+  max_retries: u32,
+  tags: Vec<String>,
+  origin: Option<Point>,
+}
+
+#[deno_bindgen(serde)]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Point {
+  x: f64,
+  y: f64,
+}
+
+#[deno_bindgen]
+fn describe_config(config: Config) -> String {
+  format!(
+    "{} ({} retries, {} tags)",
+    config.name,
+    config.max_retries,
+    config.tags.len()
+  )
+}
+
+#[deno_bindgen]
+fn default_config(name: &str) -> Config {
+  Config {
+    name: name.to_string(),
+    max_retries: 3,
+    tags: vec!["default".to_string()],
+    origin: Some(Point { x: 0.5, y: -1.0 }),
+  }
+}
+
+#[deno_bindgen]
+fn midpoint(a: Point, b: Option<Point>) -> Option<Point> {
+  let b = b?;
+  Some(Point {
+    x: (a.x + b.x) / 2.0,
+    y: (a.y + b.y) / 2.0,
+  })
+}
+
+#[deno_bindgen]
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq)]
+enum Direction {
+  North,
+  East,
+  South = 10,
+  West,
+}
+
+#[deno_bindgen]
+#[repr(i32)]
+enum Level {
+  Low = -1,
+  High = 1,
+}
+
+#[deno_bindgen]
+fn turn_right(direction: Direction) -> Direction {
+  match direction {
+    Direction::North => Direction::East,
+    Direction::East => Direction::South,
+    Direction::South => Direction::West,
+    Direction::West => Direction::North,
+  }
+}
+
+#[deno_bindgen]
+fn maybe_turn_right(direction: Option<Direction>) -> Option<Direction> {
+  Some(match direction? {
+    Direction::North => Direction::East,
+    Direction::East => Direction::South,
+    Direction::South => Direction::West,
+    Direction::West => Direction::North,
+  })
+}
+
+#[deno_bindgen]
+fn level_of(value: i32) -> Level {
+  if value < 0 {
+    Level::Low
+  } else {
+    Level::High
+  }
+}
+
+#[deno_bindgen]
+fn is_north(direction: Direction) -> bool {
+  direction == Direction::North
+}
+
+#[deno_bindgen]
+enum Event {
+  Click { x: i32, y: i32 },
+  Key(u32),
+  Text(String, bool),
+  Quit,
+}
+
+#[deno_bindgen]
+fn describe_event(event: Event) -> String {
+  match event {
+    Event::Click { x, y } => format!("click at {}, {}", x, y),
+    Event::Key(code) => format!("key {}", code),
+    Event::Text(text, shift) => format!("text {} {}", text, shift),
+    Event::Quit => "quit".to_string(),
+  }
+}
+
+#[deno_bindgen]
+fn mirror_event(event: Event) -> Event {
+  match event {
+    Event::Click { x, y } => Event::Click { x: -x, y: -y },
+    event => event,
+  }
+}
+
+#[deno_bindgen]
+fn call_twice(cb: extern "C" fn(i32) -> i32, value: i32) -> i32 {
+  cb(cb(value))
+}
+
+#[deno_bindgen]
+fn sum_by(f: impl Fn(u32) -> f64, n: u32) -> f64 {
+  (0..n).map(f).sum()
+}
+
+#[deno_bindgen(non_blocking)]
+fn find_index(pred: impl Fn(u32) -> bool, len: u32) -> Result<u32, String> {
+  (0..len).find(|i| pred(*i)).ok_or_else(|| "no match".to_string())
+}
+
+static LISTENER: Mutex<Option<extern "C" fn(u32)>> = Mutex::new(None);
+
+#[deno_bindgen(stores_callbacks)]
+fn set_listener(listener: extern "C" fn(u32)) {
+  *LISTENER.lock().unwrap() = Some(listener);
+}
+
+#[deno_bindgen]
+fn notify(value: u32) {
+  if let Some(listener) = *LISTENER.lock().unwrap() {
+    listener(value);
+  }
+}
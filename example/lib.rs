@@ -25,16 +25,25 @@ impl Context {
     }
   }
 
-  pub fn open(&mut self, vendor_id: u16, product_id: u16) -> Device {
-    let devices = self.context.devices().expect("Unable to get devices");
+  pub fn open(
+    &mut self,
+    vendor_id: u16,
+    product_id: u16,
+  ) -> Result<Device, String> {
+    let devices = self
+      .context
+      .devices()
+      .map_err(|e| format!("Unable to get devices: {:?}", e))?;
     let mut device = devices
       .into_iter()
       .find(|d| d.vendor_id == vendor_id && d.product_id == product_id)
-      .expect("Device not found.");
+      .ok_or_else(|| "Device not found.".to_string())?;
 
-    device.open().expect("Unable to open device.");
+    device
+      .open()
+      .map_err(|e| format!("Unable to open device: {:?}", e))?;
 
-    Device { device }
+    Ok(Device { device })
   }
 }
 